@@ -17,7 +17,7 @@ pub struct Args {
     /// Compression level. Uses 6 by default, like DSP does. Set it to 9 for about 5% smaller
     /// blueprints that (almost certainly) still work fine.
     #[clap(short, long, default_value_t = 6)]
-    pub compression_level: u32
+    pub compression_level: u32,
 }
 
 #[derive(Parser, Debug)]
@@ -46,6 +46,204 @@ pub struct EditArgs {
     /// Replace icon text.
     #[clap(short = 't', long)]
     pub icon_text: Option<String>,
+    /// Set proliferator mode of all production buildings and labs.
+    ///
+    /// Accepts "extra-products" or "speedup".
+    #[clap(short = 'p', long)]
+    pub proliferator_mode: Option<String>,
+    /// Set target flow of all traffic monitors, in items per minute.
+    ///
+    /// Monitor periods are kept, only target item counts change.
+    #[clap(short = 'm', long)]
+    pub monitor_flow: Option<f64>,
+    /// Set mode of all ray receivers. Accepts "power" or "photon".
+    #[clap(long)]
+    pub receiver_mode: Option<String>,
+    /// Set mode of all energy exchangers. Accepts "charge", "discharge" or "standby".
+    #[clap(long)]
+    pub exchanger_mode: Option<String>,
+    /// Set Dyson swarm orbit of all EM rail ejectors. 0 unsets it.
+    #[clap(long)]
+    pub ejector_orbit: Option<u32>,
+}
+
+#[derive(Parser, Debug)]
+#[clap()]
+pub struct StationArgs {
+    /// Only change stations that have this item in storage.
+    #[clap(short = 'w', long)]
+    pub with_ware: Option<String>,
+    /// Change settings of a ware in every station that has it. Can be given multiple times.
+    ///
+    /// Accepts format like this: "Item:local=supply,remote=demand,max=5000". Logic can be
+    /// "storage", "supply" or "demand". All settings are optional.
+    #[clap(long)]
+    pub ware: Vec<String>,
+    /// Re-route a slot. Can be given multiple times.
+    ///
+    /// Accepts format like this: "north-left=out:Item". Positions are north/east/south/west
+    /// followed by left/center/right, as seen from the station. Direction can be "in", "out"
+    /// or "none". The item must already be in storage, and can be left out to unassign the slot.
+    #[clap(long)]
+    pub slot: Vec<String>,
+    /// Max charging power, like "60MW". Also accepts W, kW and GW.
+    #[clap(short = 'C', long)]
+    pub charging: Option<String>,
+    /// Drone transport range, like "50deg".
+    #[clap(short = 'd', long)]
+    pub drone_range: Option<String>,
+    /// Vessel transport range, like "6ly". Interstellar stations only.
+    #[clap(short = 'v', long)]
+    pub vessel_range: Option<String>,
+    /// Warp enable distance, like "2au". Interstellar stations only.
+    #[clap(short = 'W', long)]
+    pub warp: Option<String>,
+    /// Whether vessels must carry space warpers. Interstellar stations only.
+    #[clap(long)]
+    pub equip_warper: Option<bool>,
+    /// Whether to take wares from orbital collectors. Interstellar stations only.
+    #[clap(long)]
+    pub orbital_collector: Option<bool>,
+    /// Minimum drone load, like "20%".
+    #[clap(long)]
+    pub drone_min_load: Option<String>,
+    /// Minimum vessel load, like "40%". Interstellar stations only.
+    #[clap(long)]
+    pub vessel_min_load: Option<String>,
+    /// Output stack count. 0 uses the researched maximum.
+    #[clap(long)]
+    pub piler_count: Option<u32>,
+}
+
+#[derive(Parser, Debug)]
+#[clap()]
+pub struct TransformArgs {
+    /// Move all buildings, and the paste cursor with them.
+    ///
+    /// Accepts format like this: "dx,dy" or "dx,dy,dz", in grid cells. One belt layer is about
+    /// 1.33 high. Sideways moves are whole cells, the cursor moves along and the drag box grows
+    /// to fit.
+    #[clap(short = 'm', long = "move", allow_hyphen_values = true)]
+    pub move_by: Option<String>,
+    /// Set the point the blueprint is held by when pasting, like "x,y". Counted in grid cells
+    /// from the drag box corner.
+    #[clap(long)]
+    pub cursor: Option<String>,
+    /// Put the paste cursor in the middle of the drag box.
+    #[clap(long, default_value_t = false)]
+    pub center_cursor: bool,
+    /// Rotate clockwise by 90, 180 or 270 degrees. Done before moving.
+    #[clap(short, long)]
+    pub rotate: Option<i32>,
+    /// Mirror along an axis: "x" flips west and east, "y" flips north and south. Done before
+    /// moving.
+    #[clap(long)]
+    pub mirror: Option<String>,
+}
+
+#[derive(Parser, Debug)]
+#[clap()]
+pub struct MergeArgs {
+    /// Blueprint files to merge. Icons and description are taken from the first one.
+    #[clap(required = true, num_args = 2..)]
+    pub files: Vec<String>,
+    /// Where to put the second, third, ... blueprint, like "x,y". Counted in grid cells from
+    /// the drag box corner of what was merged so far. By default, each goes right of the previous ones.
+    #[clap(short, long, allow_hyphen_values = true)]
+    pub at: Vec<String>,
+}
+
+#[derive(Parser, Debug)]
+#[clap()]
+pub struct CropArgs {
+    /// Cells to keep, like "x0,y0,x1,y1", corners included. Counted in grid cells from the
+    /// drag box corner.
+    #[clap(short, long = "box")]
+    pub region: String,
+}
+
+#[derive(Parser, Debug)]
+#[clap()]
+pub struct SplitArgs {
+    /// Make a blueprint of each area. With an output file "name.txt", they are written to
+    /// "name.0.txt", "name.1.txt", ...; otherwise one blueprint per line to standard output.
+    #[clap(long, required = true)]
+    pub by_area: bool,
+}
+
+#[derive(Parser, Debug)]
+#[clap()]
+pub struct FilterArgs {
+    /// Remove these buildings, like "TeslaTower,WirelessPowerTower".
+    #[clap(short = 'b', long)]
+    pub remove_building: Option<String>,
+    /// Remove buildings making these recipes, like "Gear,CircuitBoard".
+    #[clap(short = 'r', long)]
+    pub remove_recipe: Option<String>,
+    /// Remove everything but these building classes, like "Belt,Sorter".
+    #[clap(short, long)]
+    pub keep_class: Option<String>,
+}
+
+#[derive(Parser, Debug)]
+#[clap()]
+pub struct GraphArgs {
+    /// Graph format: "dot" for Graphviz, or "graphml".
+    #[clap(short, long, default_value = "dot")]
+    pub format: String,
+    /// Locale for building and recipe names. At the moment en and cn are supported. By
+    /// default, en is used.
+    #[clap(short = 'L', long)]
+    pub locale: Option<String>,
+}
+
+#[derive(Parser, Debug)]
+#[clap()]
+pub struct RenderArgs {
+    /// SVG file to write. If no file is given, SVG goes to the output.
+    #[clap(long)]
+    pub svg: Option<String>,
+    /// PNG thumbnail to write. Without a file name, it goes beside the input blueprint and is
+    /// named like it.
+    #[clap(long)]
+    pub png: Option<Option<String>>,
+    /// JPEG thumbnail to write. Without a file name, it goes beside the input blueprint and is
+    /// named like it, which is where the game's blueprint library looks for thumbnails.
+    #[clap(long)]
+    pub jpeg: Option<Option<String>>,
+    /// Pixels per cell in SVG.
+    #[clap(short, long, default_value = "16")]
+    pub scale: f32,
+    /// Width and height of thumbnails, up to 4096.
+    #[clap(long, default_value = "256")]
+    pub size: usize,
+    /// JPEG quality, from 1 to 100.
+    #[clap(short, long, default_value = "90")]
+    pub quality: u8,
+    /// Locale for recipe and ware names. At the moment en and cn are supported. By default, en
+    /// is used.
+    #[clap(short = 'L', long)]
+    pub locale: Option<String>,
+}
+
+#[derive(Parser, Debug)]
+#[clap()]
+pub struct BomArgs {
+    /// Also print the raw resources needed to craft the buildings.
+    #[clap(short, long)]
+    pub raw: bool,
+    /// Output format: text or json.
+    #[clap(short, long, default_value = "text")]
+    pub format: String,
+}
+
+#[derive(Parser, Debug)]
+#[clap()]
+pub struct RatesArgs {
+    /// Proliferator sprayed on ingredients: none, mk1, mk2 or mk3. Buildings use it for
+    /// extra products or speedup as set in the blueprint.
+    #[clap(short, long, default_value = "none")]
+    pub proliferator: String,
 }
 
 #[derive(Parser, Debug)]
@@ -70,6 +268,30 @@ pub enum Commands {
     Undump,
     /// Edit blueprint. Accepts more arguments.
     Edit(EditArgs),
+    /// Change settings of logistics stations. Accepts more arguments.
+    Station(StationArgs),
+    /// Move buildings or the paste cursor around. Accepts more arguments.
+    Transform(TransformArgs),
+    /// Merge several blueprints into one. Accepts more arguments.
+    Merge(MergeArgs),
+    /// Keep only part of a blueprint. Accepts more arguments.
+    Crop(CropArgs),
+    /// Cut a blueprint into several. Accepts more arguments.
+    Split(SplitArgs),
+    /// Remove buildings. Accepts more arguments.
+    Filter(FilterArgs),
+    /// Write the belt and sorter links between buildings as a graph. Accepts more arguments.
+    Graph(GraphArgs),
+    /// Print items produced and consumed per minute. Accepts more arguments.
+    Rates(RatesArgs),
+    /// Print power drawn and generated, per building class and in total.
+    Power,
+    /// Print the buildings needed to build a blueprint. Accepts more arguments.
+    Bom(BomArgs),
+    /// Draw a blueprint from above. Accepts more arguments.
+    Render(RenderArgs),
+    /// Look for problems in a blueprint. Exits with an error if there are any.
+    Check,
     /// Print some blueprint info.
     Info,
     /// Print item names.
@@ -54,7 +54,7 @@ impl Blueprint {
             )));
         }
         Ok((0..16)
-            .map(|x| (2 * x..2 * x + 2))
+            .map(|x| 2 * x..2 * x + 2)
             .map(|x| &d[x])
             .map(|x| u8::from_str_radix(x, 16))
             .collect::<Result<Vec<_>, _>>()?
@@ -127,8 +127,8 @@ impl Blueprint {
         let fixed0_1: u32 = Self::int(fixed0_1, "fixed0_1")?;
         let layout = Self::int(layout, "layout")?;
         let icons: Vec<u32> = icons
-            .into_iter()
-            .map(|x| Self::int(*x, "icon"))
+            .iter()
+            .map(|x| Self::int(x, "icon"))
             .collect::<Result<Vec<_>, _>>()?;
         let fixed0_2: u32 = Self::int(fixed0_2, "fixed0_2")?;
         let timestamp = Self::int(timestamp, "timestamp")?;
@@ -0,0 +1,176 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
+
+use strum::{AsRefStr, EnumIter, EnumString, IntoEnumIterator};
+
+use crate::{
+    data::{
+        enums::{DSPItem, DSPRecipe},
+        traits::{try_from_user_string_listed, TryFromUserString},
+    },
+    stats::Stats,
+};
+
+#[derive(EnumString, EnumIter, AsRefStr, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum BomFormat {
+    #[strum(serialize = "text")]
+    Text,
+    #[strum(serialize = "json")]
+    Json,
+}
+
+impl TryFromUserString for BomFormat {
+    fn try_from_user_string(s: &str) -> anyhow::Result<Self> {
+        try_from_user_string_listed(s, "bill of materials format")
+    }
+}
+
+/// What it takes to build a blueprint.
+#[derive(Default)]
+pub struct BillOfMaterials {
+    pub buildings: HashMap<DSPItem, usize>,
+    /// Raw resources, and anything else nothing makes, needed to craft the buildings. Only
+    /// filled in by [`BillOfMaterials::add_raw_resources`].
+    pub raw: HashMap<DSPItem, f64>,
+}
+
+// The recipe named like the item, or else the first one making it without using it up, so
+// reforming refined oil is not where refined oil comes from. Byproducts are not counted.
+fn recipe_for(i: DSPItem) -> Option<(DSPRecipe, u32)> {
+    let makes = |r: &DSPRecipe| {
+        let info = r.info()?;
+        let (_, k) = info.outputs.iter().find(|(o, _)| *o == i)?;
+        let consumes = info.inputs.iter().any(|(o, _)| *o == i);
+        (!consumes).then_some(*k)
+    };
+    DSPRecipe::for_item(&i)
+        .into_iter()
+        .chain(DSPRecipe::iter())
+        .find_map(|r| Some((r, makes(&r)?)))
+}
+
+fn sorted<T>(map: &HashMap<DSPItem, T>) -> Vec<(DSPItem, &T)> {
+    let mut v: Vec<_> = map.iter().map(|(i, n)| (*i, n)).collect();
+    v.sort_by_key(|(i, _)| *i as u16);
+    v
+}
+
+impl BillOfMaterials {
+    pub fn new(stats: &Stats) -> Self {
+        Self {
+            buildings: stats.buildings.clone(),
+            raw: HashMap::new(),
+        }
+    }
+
+    // Crafts `n` of `i`, down to raw resources and items with no recipe. Items already being
+    // crafted further up count as raw, so recipe loops end.
+    fn craft(&mut self, i: DSPItem, n: f64, making: &mut HashSet<DSPItem>) {
+        let made = recipe_for(i).filter(|_| !i.is_raw_resource() && !making.contains(&i));
+        let Some((recipe, k)) = made else {
+            *self.raw.entry(i).or_default() += n;
+            return;
+        };
+        making.insert(i);
+        for (input, m) in recipe.inputs().unwrap_or_default() {
+            self.craft(*input, n * *m as f64 / k as f64, making);
+        }
+        making.remove(&i);
+    }
+
+    pub fn add_raw_resources(&mut self) {
+        let buildings: Vec<(DSPItem, usize)> = sorted(&self.buildings)
+            .into_iter()
+            .map(|(i, n)| (i, *n))
+            .collect();
+        for (i, n) in buildings {
+            self.craft(i, n as f64, &mut HashSet::new());
+        }
+    }
+
+    #[cfg(feature = "dump")]
+    pub fn to_json(&self) -> anyhow::Result<String> {
+        let buildings: serde_json::Map<String, serde_json::Value> = sorted(&self.buildings)
+            .into_iter()
+            .map(|(i, n)| (i.as_ref().to_owned(), (*n).into()))
+            .collect();
+        let raw: serde_json::Map<String, serde_json::Value> = sorted(&self.raw)
+            .into_iter()
+            .map(|(i, n)| (i.as_ref().to_owned(), (*n).into()))
+            .collect();
+        let mut out = serde_json::Map::new();
+        out.insert("buildings".to_owned(), buildings.into());
+        if !raw.is_empty() {
+            out.insert("raw_resources".to_owned(), raw.into());
+        }
+        Ok(serde_json::to_string_pretty(&out)?)
+    }
+}
+
+impl Display for BillOfMaterials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Buildings:")?;
+        for (i, n) in sorted(&self.buildings) {
+            writeln!(f, "{}: {}", i.as_ref(), n)?;
+        }
+        if !self.raw.is_empty() {
+            writeln!(f)?;
+            writeln!(f, "Raw resources:")?;
+            for (i, n) in sorted(&self.raw) {
+                writeln!(f, "{}: {:.2}", i.as_ref(), n)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        data::{enums::DSPItem, visit::Visitor},
+        edit::stats::GetStats,
+        testutil::example_station,
+    };
+
+    use super::BillOfMaterials;
+
+    #[test]
+    fn bill_of_materials() {
+        let mut stats = GetStats::new();
+        stats.visit_blueprint(&mut example_station());
+        let mut bom = BillOfMaterials::new(&stats.0);
+        assert_eq!(bom.buildings[&DSPItem::InterstellarLogisticsStation], 1);
+        assert!(bom.raw.is_empty());
+
+        // Three belts are two ingots and a gear, one ore each.
+        bom.buildings = [(DSPItem::ConveyorBeltMKI, 3)].into();
+        bom.add_raw_resources();
+        assert_eq!(bom.raw, [(DSPItem::IronOre, 3.0)].into());
+        assert!(bom.to_string().ends_with("Raw resources:\nIronOre: 3.00\n"));
+        #[cfg(feature = "dump")]
+        assert!(bom.to_json().unwrap().contains("\"IronOre\": 3.0"));
+
+        // Eight gears and four circuit boards, which take two copper ingots.
+        bom.buildings = [(DSPItem::AssemblingMachineMkI, 1)].into();
+        bom.raw.clear();
+        bom.add_raw_resources();
+        let mk1 = [(DSPItem::IronOre, 16.0), (DSPItem::CopperOre, 2.0)];
+        assert_eq!(bom.raw, mk1.into());
+
+        // Four processors take 32 silicon ore, which is mined rather than made from stone.
+        // Graphene is made from coal and sulfuric acid, which is pumped.
+        bom.buildings = [(DSPItem::AssemblingMachineMkII, 1)].into();
+        bom.raw.clear();
+        bom.add_raw_resources();
+        let mk2 = [
+            (DSPItem::IronOre, 24.0),
+            (DSPItem::CopperOre, 14.0),
+            (DSPItem::SiliconOre, 32.0),
+            (DSPItem::Coal, 24.0),
+            (DSPItem::SulfuricAcid, 4.0),
+        ];
+        assert_eq!(bom.raw, mk2.into());
+    }
+}
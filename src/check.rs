@@ -0,0 +1,397 @@
+use std::{collections::HashMap, fmt::Display};
+
+use crate::{
+    blueprint::Blueprint,
+    data::{
+        belt::Belt,
+        building::Building,
+        defense::{BattleBase, Turret},
+        enums::{DSPIcon, DSPItem, DSPRecipe},
+        monitor::TrafficMonitor,
+        power::{FuelGenerator, RayReceiver},
+        recipe::Facility,
+        splitter::Splitter,
+        station::Station,
+        storage::{Depot, Distributor, StorageTank},
+        traits::{IconId, ItemId},
+        visit::{Visit, Visitor},
+    },
+    graph::{End, Graph},
+    render::{direction, footprint},
+};
+
+pub struct Problem {
+    /// Index of the building the problem is with, if any.
+    pub building: Option<u32>,
+    pub message: String,
+}
+
+impl Display for Problem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.building {
+            Some(i) => write!(f, "Building {}: {}", i, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+// Buildings may touch, but not overlap by more than this many cells.
+const OVERLAP_SLACK: f32 = 0.01;
+// Buildings whose heights differ by this much are stacked, like labs, not overlapping.
+const STACKED: f32 = 0.5;
+
+#[derive(Default)]
+struct FindUnknownIds {
+    building: Option<u32>,
+    problems: Vec<Problem>,
+}
+
+impl FindUnknownIds {
+    fn report(&mut self, message: String) {
+        self.problems.push(Problem {
+            building: self.building,
+            message,
+        })
+    }
+
+    fn item<T: TryInto<DSPItem> + Copy + Into<u32>>(&mut self, what: &str, t: T) {
+        if t.try_into().is_err() {
+            self.report(format!("unknown {} item {}", what, t.into()));
+        }
+    }
+
+    // 0 means "no item".
+    fn filter(&mut self, what: &str, t: ItemId<u32>) {
+        if t.0 != 0 {
+            self.item(what, t.0)
+        }
+    }
+
+    fn icon(&mut self, what: &str, t: IconId<u32>) {
+        if let Ok(DSPIcon::Unknown(_)) | Err(_) = DSPIcon::try_from(t.0) {
+            self.report(format!("unknown {} icon {}", what, t.0));
+        }
+    }
+}
+
+impl Visitor for FindUnknownIds {
+    fn visit_blueprint(&mut self, v: &mut Blueprint) {
+        for icon in v.icons {
+            if icon != 0 {
+                self.icon("blueprint", IconId(icon));
+            }
+        }
+        v.visit(self)
+    }
+
+    fn visit_building(&mut self, v: &mut Building) {
+        let h = &v.header;
+        self.building = Some(h.index);
+        if v.kind().is_err() {
+            self.report(format!("unknown building item {}", h.item_id.0));
+        }
+        if h.recipe_id.0 != 0 && v.recipe().is_err() {
+            self.report(format!("unknown recipe {}", h.recipe_id.0));
+        }
+        if h.filter_id.0 != 0 {
+            self.item("filter", h.filter_id.0);
+        }
+        v.visit(self)
+    }
+
+    fn visit_station(&mut self, v: &mut Station) {
+        for s in v.valid_storage() {
+            if s.item_id.0 != 0 {
+                self.item("station storage", s.item_id.0);
+            }
+        }
+        v.visit(self)
+    }
+
+    fn visit_belt(&mut self, v: &mut Belt) {
+        if v.label.0 != 0 {
+            self.icon("belt label", v.label);
+        }
+        v.visit(self)
+    }
+
+    fn visit_splitter(&mut self, v: &mut Splitter) {
+        self.filter("splitter filter", v.filter);
+        v.visit(self)
+    }
+
+    fn visit_traffic_monitor(&mut self, v: &mut TrafficMonitor) {
+        self.filter("traffic monitor filter", v.cargo_filter);
+        if v.alarm_signal.0 != 0 {
+            self.icon("traffic monitor alarm", v.alarm_signal);
+        }
+        v.visit(self)
+    }
+
+    fn visit_fuel_generator(&mut self, v: &mut FuelGenerator) {
+        self.filter("fuel", v.fuel);
+        v.visit(self)
+    }
+
+    fn visit_ray_receiver(&mut self, v: &mut RayReceiver) {
+        self.filter("lens", v.lens);
+        v.visit(self)
+    }
+
+    fn visit_turret(&mut self, v: &mut Turret) {
+        self.filter("ammo", v.ammo);
+        v.visit(self)
+    }
+
+    fn visit_battle_base(&mut self, v: &mut BattleBase) {
+        self.filter("drop filter", v.drop_filter);
+        v.visit(self)
+    }
+
+    fn visit_depot(&mut self, v: &mut Depot) {
+        for f in v.filters.iter() {
+            self.filter("depot filter", *f);
+        }
+        v.visit(self)
+    }
+
+    fn visit_storage_tank(&mut self, v: &mut StorageTank) {
+        self.filter("tank fluid", v.fluid);
+        v.visit(self)
+    }
+
+    fn visit_distributor(&mut self, v: &mut Distributor) {
+        self.filter("distributor", v.item_id);
+        v.visit(self)
+    }
+}
+
+fn check_counts(bp: &Blueprint, problems: &mut Vec<Problem>) {
+    let d = &bp.data;
+    let mut report = |message: String| {
+        problems.push(Problem {
+            building: None,
+            message,
+        })
+    };
+    if d.building_count as usize != d.buildings.len() {
+        report(format!(
+            "Building count is {}, but there are {} buildings.",
+            d.building_count,
+            d.buildings.len()
+        ));
+    }
+    if d.header.area_count as usize != d.areas.len() {
+        report(format!(
+            "Area count is {}, but there are {} areas.",
+            d.header.area_count,
+            d.areas.len()
+        ));
+    }
+    let mut seen = HashMap::new();
+    for b in d.buildings.iter() {
+        *seen.entry(b.header.index).or_insert(0) += 1;
+        if !d.areas.iter().any(|a| a.index == b.header.area_index) {
+            report(format!(
+                "Building {} is in area {}, which does not exist.",
+                b.header.index, b.header.area_index
+            ));
+        }
+    }
+    let mut dupes: Vec<_> = seen.into_iter().filter(|(_, n)| *n > 1).collect();
+    dupes.sort();
+    for (i, n) in dupes {
+        report(format!("Building index {} is used {} times.", i, n));
+    }
+}
+
+fn check_recipe(b: &Building) -> Option<String> {
+    let recipe: DSPRecipe = b.recipe().ok()?;
+    let kind = b.kind().ok()?;
+    let needed = recipe.facility()?;
+    match Facility::of_building(kind) {
+        Some(f) if f == needed => None,
+        Some(f) => Some(format!(
+            "recipe {} needs facility {}, but {} is {}",
+            recipe.as_ref(),
+            needed.as_ref(),
+            kind.as_ref(),
+            f.as_ref()
+        )),
+        None => Some(format!(
+            "{} has recipe {}, but makes no recipes",
+            kind.as_ref(),
+            recipe.as_ref()
+        )),
+    }
+}
+
+// Bounding box of a building's footprint, turned by its yaw: centre, then width and height.
+fn bounds(b: &Building) -> Option<[f32; 5]> {
+    let kind = b.kind().ok()?;
+    // Belts and sorters sit next to, under and between other buildings.
+    if kind.is_belt() || kind.is_sorter() {
+        return None;
+    }
+    let h = &b.header;
+    let (w, d) = footprint(kind);
+    let (sin, cos) = direction(h.yaw);
+    Some([
+        h.local_offset_x,
+        h.local_offset_y,
+        h.local_offset_z,
+        w * cos.abs() + d * sin.abs(),
+        w * sin.abs() + d * cos.abs(),
+    ])
+}
+
+fn check_overlaps(bp: &Blueprint, problems: &mut Vec<Problem>) {
+    let mut boxes: Vec<_> = bp
+        .data
+        .buildings
+        .iter()
+        .filter_map(|b| Some((b.header.index, bounds(b)?)))
+        .collect();
+    boxes.sort_by(|a, b| a.1[0].total_cmp(&b.1[0]));
+    let widest = boxes.iter().map(|(_, b)| b[3]).fold(0.0, f32::max);
+    let mut found = vec![];
+    for (i, (a, [ax, ay, az, aw, ah])) in boxes.iter().enumerate() {
+        for (b, [bx, by, bz, bw, bh]) in boxes[i + 1..].iter() {
+            if bx - ax >= widest {
+                break;
+            }
+            if (bx - ax).abs() < (aw + bw) / 2.0 - OVERLAP_SLACK
+                && (by - ay).abs() < (ah + bh) / 2.0 - OVERLAP_SLACK
+                && (bz - az).abs() < STACKED
+            {
+                found.push((*a.max(b), *a.min(b)));
+            }
+        }
+    }
+    found.sort();
+    for (b, other) in found {
+        problems.push(Problem {
+            building: Some(b),
+            message: format!("overlaps building {}", other),
+        });
+    }
+}
+
+/// Looks for things in the blueprint the game would not like, or that this crate does not
+/// know about.
+pub fn check(bp: &mut Blueprint) -> Vec<Problem> {
+    let mut problems = vec![];
+    check_counts(bp, &mut problems);
+
+    for l in Graph::new(&bp.data).broken {
+        let end = match l.end {
+            End::Input => "input",
+            End::Output => "output",
+        };
+        problems.push(Problem {
+            building: Some(l.building),
+            message: format!(
+                "{} connects to building {}, which does not exist",
+                end, l.target
+            ),
+        });
+    }
+
+    for b in bp.data.buildings.iter() {
+        if let Some(message) = check_recipe(b) {
+            problems.push(Problem {
+                building: Some(b.header.index),
+                message,
+            });
+        }
+    }
+
+    check_overlaps(bp, &mut problems);
+
+    let mut unknown = FindUnknownIds::default();
+    unknown.visit_blueprint(bp);
+    problems.extend(unknown.problems);
+    problems
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        data::{
+            building::BuildingParam,
+            enums::{DSPItem, DSPRecipe},
+            traits::ItemId,
+        },
+        testutil::{building, example_station},
+    };
+
+    use super::check;
+
+    #[test]
+    fn example_is_fine() {
+        assert!(check(&mut example_station()).is_empty());
+    }
+
+    #[test]
+    fn problems() {
+        let mut bp = example_station();
+        let d = &mut bp.data;
+        d.building_count += 1;
+        d.buildings[0].header.output_object_index = 99;
+        d.buildings[1].header.item_id = DSPItem::ArcSmelter.into();
+        d.buildings[1].header.recipe_id = DSPRecipe::Gear.into();
+        d.buildings[2].header.recipe_id = DSPRecipe::IronIngot.into();
+        if let BuildingParam::Station(s) = &mut d.buildings[12].param {
+            s.storage[0].item_id = ItemId(4242);
+        }
+        let problems: Vec<String> = check(&mut bp).iter().map(|p| p.to_string()).collect();
+        assert_eq!(
+            problems,
+            vec![
+                "Building count is 38, but there are 37 buildings.",
+                "Building 0: output connects to building 99, which does not exist",
+                "Building 1: recipe Gear needs facility Assembler, but ArcSmelter is Smelter",
+                "Building 2: ConveyorBeltMKII has recipe IronIngot, but makes no recipes",
+                "Building 12: unknown station storage item 4242",
+            ]
+        );
+    }
+
+    #[test]
+    fn overlaps() {
+        let mut bp = example_station();
+        let d = &mut bp.data;
+        let n = d.buildings.len() as u32;
+        // Two assemblers a cell apart, one touching them, a lab stacked on another and an
+        // assembler over belt 8.
+        let at = [
+            (DSPItem::AssemblingMachineMkI, 20.0, 20.0, 0.0, 0.0),
+            (DSPItem::AssemblingMachineMkI, 21.0, 20.0, 0.0, 0.0),
+            (DSPItem::AssemblingMachineMkI, 20.0, 23.0, 0.0, 0.0),
+            (DSPItem::MatrixLab, 30.0, 30.0, 0.0, 0.0),
+            (DSPItem::MatrixLab, 30.0, 30.0, 3.0, 0.0),
+            (DSPItem::AssemblingMachineMkI, 4.0, 0.0, 0.0, 0.0),
+            // Chemical plants are 3 by 2, turned they reach 1.5 cells north and south.
+            (DSPItem::ChemicalPlant, 40.0, 40.0, 0.0, 90.0),
+            (DSPItem::ChemicalPlant, 40.0, 42.5, 0.0, 90.0),
+        ];
+        for (i, (item, x, y, z, yaw)) in at.into_iter().enumerate() {
+            let mut b = building(item, &[]);
+            let h = &mut b.header;
+            h.index = n + i as u32;
+            (h.local_offset_x, h.local_offset_y, h.local_offset_z) = (x, y, z);
+            (h.local_offset_x2, h.local_offset_y2, h.local_offset_z2) = (x, y, z);
+            (h.yaw, h.yaw2) = (yaw, yaw);
+            d.buildings.push(b);
+        }
+        d.building_count = d.buildings.len() as u32;
+        let problems: Vec<String> = check(&mut bp).iter().map(|p| p.to_string()).collect();
+        assert_eq!(
+            problems,
+            vec![
+                format!("Building {}: overlaps building {}", n + 1, n),
+                format!("Building {}: overlaps building {}", n + 7, n + 6),
+            ]
+        );
+    }
+}
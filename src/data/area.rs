@@ -5,18 +5,18 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature = "dump", derive(Serialize, Deserialize))]
 #[derive(BinRead, BinWrite)]
 pub struct Area {
-    index: i8,
-    parent_index: i8,
+    pub index: i8,
+    pub parent_index: i8,
     #[br(little)]
-    tropic_anchor: u16,
+    pub tropic_anchor: u16,
     #[br(little)]
-    area_segments: u16,
+    pub area_segments: u16,
     #[br(little)]
-    anchor_local_offset_x: u16,
+    pub anchor_local_offset_x: u16,
     #[br(little)]
-    anchor_local_offset_y: u16,
+    pub anchor_local_offset_y: u16,
     #[br(little)]
-    width: u16,
+    pub width: u16,
     #[br(little)]
-    height: u16,
+    pub height: u16,
 }
@@ -10,20 +10,20 @@ use super::visit::{Visit, Visitor};
 #[derive(BinRead, BinWrite)]
 pub struct Header {
     #[br(little)]
-    version: u32,
+    pub version: u32,
     #[br(little)]
-    cursor_offset_x: u32,
+    pub cursor_offset_x: u32,
     #[br(little)]
-    cursor_offset_y: u32,
+    pub cursor_offset_y: u32,
     #[br(little)]
-    cursor_target_area: u32,
+    pub cursor_target_area: u32,
     #[br(little)]
-    dragbox_size_x: u32,
+    pub dragbox_size_x: u32,
     #[br(little)]
-    dragbox_size_y: u32,
+    pub dragbox_size_y: u32,
     #[br(little)]
-    primary_area_index: u32,
-    area_count: u8,
+    pub primary_area_index: u32,
+    pub area_count: u8,
 }
 
 #[cfg_attr(feature = "dump", derive(Serialize, Deserialize))]
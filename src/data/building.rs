@@ -5,8 +5,17 @@ use serde::{Deserialize, Serialize};
 
 use super::{
     belt::Belt,
-    enums::DSPItem,
+    defense::{BattleBase, DefenseTower, Turret},
+    dyson::{Ejector, Silo},
+    enums::{DSPItem, DSPRecipe},
+    monitor::TrafficMonitor,
+    piler::Piler,
+    power::{Accumulator, EnergyExchanger, FuelGenerator, RayReceiver},
+    production::{LabParams, ProductionParams},
+    sorter::Sorter,
+    splitter::Splitter,
     station::Station,
+    storage::{Depot, Distributor, StorageTank},
     traits::{BPModelId, ItemId, RecipeId},
     visit::{Visit, Visitor},
 };
@@ -22,15 +31,119 @@ fn b_is(i: ItemId<u16>, f: fn(&DSPItem) -> bool) -> bool {
 pub enum BuildingParam {
     #[br(pre_assert(b_is(building, DSPItem::is_station)))]
     Station(
-        #[br(args { is_interstellar: b_is(building, DSPItem::is_interstellar_station), param_count: param_count })]
+        #[br(args { is_interstellar: b_is(building, DSPItem::is_interstellar_station), is_collector: false, param_count: param_count })]
          Station,
     ),
+    #[br(pre_assert(b_is(building, DSPItem::is_orbital_collector)))]
+    OrbitalCollector(
+        #[br(args { is_interstellar: true, is_collector: true, param_count: param_count })] Station,
+    ),
     #[br(pre_assert(b_is(building, DSPItem::is_belt)))]
     Belt(
         #[br(if(param_count != 0))]
         #[br(args(param_count))]
         Option<Belt>,
     ),
+    #[br(pre_assert(b_is(building, DSPItem::is_sorter)))]
+    Sorter(
+        #[br(if(param_count != 0))]
+        #[br(args(param_count))]
+        Option<Sorter>,
+    ),
+    #[br(pre_assert(b_is(building, DSPItem::is_splitter)))]
+    Splitter(
+        #[br(if(param_count != 0))]
+        #[br(args(param_count))]
+        Option<Splitter>,
+    ),
+    #[br(pre_assert(b_is(building, DSPItem::is_piler)))]
+    Piler(
+        #[br(if(param_count != 0))]
+        #[br(args(param_count))]
+        Option<Piler>,
+    ),
+    #[br(pre_assert(b_is(building, DSPItem::is_production)))]
+    Production(
+        #[br(if(param_count != 0))]
+        #[br(args(param_count))]
+        Option<ProductionParams>,
+    ),
+    #[br(pre_assert(b_is(building, DSPItem::is_lab)))]
+    Lab(
+        #[br(if(param_count != 0))]
+        #[br(args(param_count))]
+        Option<LabParams>,
+    ),
+    #[br(pre_assert(b_is(building, DSPItem::is_depot)))]
+    Depot(
+        #[br(if(param_count != 0))]
+        #[br(args(param_count))]
+        Option<Depot>,
+    ),
+    #[br(pre_assert(b_is(building, DSPItem::is_tank)))]
+    StorageTank(
+        #[br(if(param_count != 0))]
+        #[br(args(param_count))]
+        Option<StorageTank>,
+    ),
+    #[br(pre_assert(b_is(building, DSPItem::is_distributor)))]
+    Distributor(
+        #[br(if(param_count != 0))]
+        #[br(args(param_count))]
+        Option<Distributor>,
+    ),
+    #[br(pre_assert(b_is(building, DSPItem::is_traffic_monitor)))]
+    TrafficMonitor(
+        #[br(if(param_count != 0))]
+        #[br(args(param_count))]
+        Option<TrafficMonitor>,
+    ),
+    #[br(pre_assert(b_is(building, DSPItem::is_fuel_generator)))]
+    FuelGenerator(
+        #[br(if(param_count != 0))]
+        #[br(args(param_count))]
+        Option<FuelGenerator>,
+    ),
+    #[br(pre_assert(b_is(building, DSPItem::is_ray_receiver)))]
+    RayReceiver(
+        #[br(if(param_count != 0))]
+        #[br(args(param_count))]
+        Option<RayReceiver>,
+    ),
+    #[br(pre_assert(b_is(building, DSPItem::is_energy_exchanger)))]
+    EnergyExchanger(
+        #[br(if(param_count != 0))]
+        #[br(args(param_count))]
+        Option<EnergyExchanger>,
+    ),
+    #[br(pre_assert(b_is(building, DSPItem::is_accumulator)))]
+    Accumulator(#[br(args(param_count))] Accumulator),
+    #[br(pre_assert(b_is(building, DSPItem::is_ejector)))]
+    Ejector(
+        #[br(if(param_count != 0))]
+        #[br(args(param_count))]
+        Option<Ejector>,
+    ),
+    #[br(pre_assert(b_is(building, DSPItem::is_silo)))]
+    Silo(#[br(args(param_count))] Silo),
+    #[br(pre_assert(b_is(building, DSPItem::is_turret)))]
+    Turret(
+        #[br(if(param_count != 0))]
+        #[br(args(param_count))]
+        Option<Turret>,
+    ),
+    #[br(pre_assert(b_is(building, DSPItem::is_defense_tower)))]
+    DefenseTower(
+        #[br(if(param_count != 0))]
+        #[br(args(param_count))]
+        Option<DefenseTower>,
+    ),
+    #[br(pre_assert(b_is(building, DSPItem::is_battle_base)))]
+    BattleBase(
+        #[br(if(param_count != 0))]
+        #[br(args(param_count))]
+        Option<BattleBase>,
+    ),
     Unknown(
         #[br(count = param_count)]
         #[br(little)]
@@ -94,14 +207,235 @@ impl Building {
     pub fn kind(&self) -> Result<DSPItem, TryFromPrimitiveError<DSPItem>> {
         DSPItem::try_from(self.header.item_id)
     }
+
+    pub fn recipe(&self) -> Result<DSPRecipe, TryFromPrimitiveError<DSPRecipe>> {
+        DSPRecipe::try_from(self.header.recipe_id)
+    }
 }
 
 impl Visit for Building {
     fn visit<T: Visitor + ?Sized>(&mut self, visitor: &mut T) {
         match &mut self.param {
             BuildingParam::Station(s) => visitor.visit_station(s),
+            BuildingParam::OrbitalCollector(s) => visitor.visit_orbital_collector(s),
             BuildingParam::Belt(Some(b)) => visitor.visit_belt(b),
+            BuildingParam::Sorter(Some(s)) => visitor.visit_sorter(s),
+            BuildingParam::Splitter(Some(s)) => visitor.visit_splitter(s),
+            BuildingParam::Piler(Some(p)) => visitor.visit_piler(p),
+            BuildingParam::Production(Some(p)) => visitor.visit_production(p),
+            BuildingParam::Lab(Some(l)) => visitor.visit_lab(l),
+            BuildingParam::Depot(Some(d)) => visitor.visit_depot(d),
+            BuildingParam::StorageTank(Some(t)) => visitor.visit_storage_tank(t),
+            BuildingParam::Distributor(Some(d)) => visitor.visit_distributor(d),
+            BuildingParam::TrafficMonitor(Some(m)) => visitor.visit_traffic_monitor(m),
+            BuildingParam::FuelGenerator(Some(g)) => visitor.visit_fuel_generator(g),
+            BuildingParam::RayReceiver(Some(r)) => visitor.visit_ray_receiver(r),
+            BuildingParam::EnergyExchanger(Some(e)) => visitor.visit_energy_exchanger(e),
+            BuildingParam::Ejector(Some(e)) => visitor.visit_ejector(e),
+            BuildingParam::Turret(Some(t)) => visitor.visit_turret(t),
+            BuildingParam::DefenseTower(Some(t)) => visitor.visit_defense_tower(t),
+            BuildingParam::BattleBase(Some(b)) => visitor.visit_battle_base(b),
             _ => (),
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+
+    use binrw::{BinReaderExt, BinWrite};
+
+    use crate::{
+        data::{
+            building::BuildingParam,
+            enums::DSPItem,
+            power::{ExchangerMode, RayReceiverMode},
+            production::ProliferatorMode,
+        },
+        testutil::raw_building,
+    };
+
+    use super::Building;
+
+    fn roundtrip(item: DSPItem, params: &[u32]) -> Building {
+        let raw = raw_building(item, params);
+        let b: Building = Cursor::new(&raw).read_le().unwrap();
+        let mut back = vec![];
+        b.write_le(&mut Cursor::new(&mut back)).unwrap();
+        assert_eq!(raw, back);
+        b
+    }
+
+    #[test]
+    fn sorter_splitter_piler() {
+        match roundtrip(DSPItem::SorterMKIII, &[2]).param {
+            BuildingParam::Sorter(Some(s)) => assert_eq!(s.length, 2),
+            _ => panic!(),
+        }
+        match roundtrip(DSPItem::Splitter, &[1, 0, DSPItem::Gear as u32, 7]).param {
+            BuildingParam::Splitter(Some(s)) => {
+                assert_eq!(s.input_priority, 1);
+                assert_eq!(s.output_priority, 0);
+                assert_eq!(s.filter.0, DSPItem::Gear as u32);
+                assert_eq!(s.unknown, [7]);
+            }
+            _ => panic!(),
+        }
+        match roundtrip(DSPItem::AutomaticPiler, &[4, 1]).param {
+            BuildingParam::Piler(Some(p)) => {
+                assert_eq!(p.stack_count, 4);
+                assert_eq!(p.direction, 1);
+            }
+            _ => panic!(),
+        }
+        assert!(matches!(
+            roundtrip(DSPItem::SorterMKI, &[]).param,
+            BuildingParam::Sorter(None)
+        ));
+        // Too short to decode, kept as is.
+        assert!(matches!(
+            roundtrip(DSPItem::Splitter, &[1]).param,
+            BuildingParam::Unknown(_)
+        ));
+    }
+
+    #[test]
+    fn production_and_lab() {
+        match roundtrip(DSPItem::AssemblingMachineMkIII, &[1]).param {
+            BuildingParam::Production(Some(p)) => {
+                assert_eq!(p.proliferator_mode, ProliferatorMode::Speedup)
+            }
+            _ => panic!(),
+        }
+        match roundtrip(DSPItem::MatrixLab, &[1, 0]).param {
+            BuildingParam::Lab(Some(l)) => {
+                assert_eq!(l.research_mode, 1);
+                assert_eq!(l.proliferator_mode, ProliferatorMode::ExtraProducts);
+            }
+            _ => panic!(),
+        }
+        // Unknown mode, kept as is.
+        assert!(matches!(
+            roundtrip(DSPItem::ArcSmelter, &[5]).param,
+            BuildingParam::Unknown(_)
+        ));
+    }
+
+    #[test]
+    fn storage() {
+        let iron = DSPItem::IronOre as u32;
+        match roundtrip(DSPItem::DepotMKI, &[10, 0, iron, iron]).param {
+            BuildingParam::Depot(Some(d)) => {
+                assert_eq!(d.bans, 10);
+                assert_eq!(
+                    d.filters.iter().map(|f| f.0).collect::<Vec<_>>(),
+                    [0, iron, iron]
+                );
+            }
+            _ => panic!(),
+        }
+        match roundtrip(DSPItem::StorageTank, &[1, 0, DSPItem::Water as u32]).param {
+            BuildingParam::StorageTank(Some(t)) => assert_eq!(t.fluid.0, DSPItem::Water as u32),
+            _ => panic!(),
+        }
+        match roundtrip(DSPItem::LogisticsDistributor, &[iron, 1, 2, 100_000, 1, 0]).param {
+            BuildingParam::Distributor(Some(d)) => {
+                assert_eq!(d.item_id.0, iron);
+                assert_eq!(d.logic, 1);
+                assert_eq!(d.icarus_logic, 2);
+            }
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn traffic_monitor() {
+        let params = [30, 600, 3, 1, 2, DSPItem::Gear as u32, 0, 1, 501, 0, 9];
+        match roundtrip(DSPItem::TrafficMonitor, &params).param {
+            BuildingParam::TrafficMonitor(Some(mut m)) => {
+                assert_eq!(m.cargo_filter.0, DSPItem::Gear as u32);
+                assert_eq!(m.alarm_signal.0, 501);
+                assert_eq!(m.unknown, [9]);
+                assert_eq!(m.flow_per_minute(), 180.0);
+                m.set_flow_per_minute(360.0);
+                assert_eq!(m.target_cargo_amount, 60);
+            }
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn power() {
+        match roundtrip(
+            DSPItem::MiniFusionPowerPlant,
+            &[DSPItem::DeuteronFuelRod as u32],
+        )
+        .param
+        {
+            BuildingParam::FuelGenerator(Some(g)) => {
+                assert_eq!(g.fuel.0, DSPItem::DeuteronFuelRod as u32)
+            }
+            _ => panic!(),
+        }
+        match roundtrip(DSPItem::RayReceiver, &[1, DSPItem::GravitonLens as u32]).param {
+            BuildingParam::RayReceiver(Some(r)) => assert_eq!(r.mode, RayReceiverMode::Photon),
+            _ => panic!(),
+        }
+        match roundtrip(DSPItem::EnergyExchanger, &[u32::MAX]).param {
+            BuildingParam::EnergyExchanger(Some(e)) => assert_eq!(e.mode, ExchangerMode::Discharge),
+            _ => panic!(),
+        }
+        assert!(matches!(
+            roundtrip(DSPItem::AccumulatorFull, &[1, 2]).param,
+            BuildingParam::Accumulator(_)
+        ));
+    }
+
+    #[test]
+    fn ejector_and_silo() {
+        match roundtrip(DSPItem::EMRailEjector, &[3]).param {
+            BuildingParam::Ejector(Some(e)) => assert_eq!(e.orbit, 3),
+            _ => panic!(),
+        }
+        assert!(matches!(
+            roundtrip(DSPItem::VerticalLaunchingSilo, &[]).param,
+            BuildingParam::Silo(_)
+        ));
+    }
+
+    #[test]
+    fn orbital_collector() {
+        let mut params = vec![0; 2048];
+        params[0] = DSPItem::Hydrogen as u32;
+        params[6] = DSPItem::Deuterium as u32;
+        match roundtrip(DSPItem::OrbitalCollector, &params).param {
+            BuildingParam::OrbitalCollector(s) => {
+                let items: Vec<u32> = s.valid_storage().iter().map(|s| s.item_id.0).collect();
+                assert_eq!(items, [DSPItem::Hydrogen as u32, DSPItem::Deuterium as u32]);
+            }
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn defense() {
+        let ammo = DSPItem::SuperalloyAmmoBox as u32;
+        match roundtrip(DSPItem::GaussTurret, &[2, 3, ammo]).param {
+            BuildingParam::Turret(Some(t)) => {
+                assert_eq!(t.group, 2);
+                assert_eq!(t.target_priority, 3);
+                assert_eq!(t.ammo.0, ammo);
+            }
+            _ => panic!(),
+        }
+        match roundtrip(DSPItem::PlanetaryShieldGenerator, &[1, 5]).param {
+            BuildingParam::DefenseTower(Some(t)) => assert_eq!(t.group, 1),
+            _ => panic!(),
+        }
+        match roundtrip(DSPItem::BattlefieldAnalysisBase, &[1, 1, 0]).param {
+            BuildingParam::BattleBase(Some(b)) => assert_eq!(b.auto_pickup, 1),
+            _ => panic!(),
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use binrw::{BinRead, BinWrite};
+#[cfg(feature = "dump")]
+use serde::{Deserialize, Serialize};
+
+use super::{
+    traits::ItemId,
+    visit::{Visit, Visitor},
+};
+
+#[cfg_attr(feature = "dump", derive(Serialize, Deserialize))]
+#[derive(BinRead, BinWrite)]
+#[br(import(param_count: usize))]
+#[br(pre_assert(param_count >= 3))]
+pub struct Turret {
+    #[br(little)]
+    pub group: u32, // Defense group, 0 if none
+    #[br(little)]
+    pub target_priority: u32, // Bit flags. 1 - ground units, 2 - air units, 4 - bases, 8 - relays
+    #[br(little)]
+    pub ammo: ItemId<u32>, // 0 accepts any ammo the turret can use
+    #[br(count = param_count - 3)]
+    #[br(little)]
+    pub unknown: Vec<u32>,
+}
+
+impl Visit for Turret {
+    fn visit<T: Visitor + ?Sized>(&mut self, _visitor: &mut T) {}
+}
+
+// Jammer and signal towers, shield generators.
+#[cfg_attr(feature = "dump", derive(Serialize, Deserialize))]
+#[derive(BinRead, BinWrite)]
+#[br(import(param_count: usize))]
+#[br(pre_assert(param_count >= 1))]
+pub struct DefenseTower {
+    #[br(little)]
+    pub group: u32, // Defense group, 0 if none
+    #[br(count = param_count - 1)]
+    #[br(little)]
+    pub unknown: Vec<u32>,
+}
+
+impl Visit for DefenseTower {
+    fn visit<T: Visitor + ?Sized>(&mut self, _visitor: &mut T) {}
+}
+
+#[cfg_attr(feature = "dump", derive(Serialize, Deserialize))]
+#[derive(BinRead, BinWrite)]
+#[br(import(param_count: usize))]
+#[br(pre_assert(param_count >= 3))]
+pub struct BattleBase {
+    #[br(little)]
+    pub auto_construct: u32,
+    #[br(little)]
+    pub auto_pickup: u32, // Picks up dark fog drops
+    #[br(little)]
+    pub drop_filter: ItemId<u32>, // Only pick up this item, 0 picks up everything
+    #[br(count = param_count - 3)]
+    #[br(little)]
+    pub unknown: Vec<u32>,
+}
+
+impl Visit for BattleBase {
+    fn visit<T: Visitor + ?Sized>(&mut self, _visitor: &mut T) {}
+}
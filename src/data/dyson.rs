@@ -0,0 +1,35 @@
+use binrw::{BinRead, BinWrite};
+#[cfg(feature = "dump")]
+use serde::{Deserialize, Serialize};
+
+use super::visit::{Visit, Visitor};
+
+#[cfg_attr(feature = "dump", derive(Serialize, Deserialize))]
+#[derive(BinRead, BinWrite)]
+#[br(import(param_count: usize))]
+#[br(pre_assert(param_count >= 1))]
+pub struct Ejector {
+    #[br(little)]
+    pub orbit: u32, // Dyson swarm orbit index, 0 if none is selected
+    #[br(count = param_count - 1)]
+    #[br(little)]
+    pub unknown: Vec<u32>,
+}
+
+impl Visit for Ejector {
+    fn visit<T: Visitor + ?Sized>(&mut self, _visitor: &mut T) {}
+}
+
+// Rockets pick their layer on their own, nothing to set here.
+#[cfg_attr(feature = "dump", derive(Serialize, Deserialize))]
+#[derive(BinRead, BinWrite)]
+#[br(import(param_count: usize))]
+pub struct Silo {
+    #[br(count = param_count)]
+    #[br(little)]
+    pub unknown: Vec<u32>,
+}
+
+impl Visit for Silo {
+    fn visit<T: Visitor + ?Sized>(&mut self, _visitor: &mut T) {}
+}
@@ -1,6 +1,8 @@
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use std::fmt::Debug;
-use strum::{AsRefStr, EnumIter, EnumString};
+use strum::{AsRefStr, EnumIter, EnumString, IntoEnumIterator};
+
+use super::traits::{try_from_user_string_listed, TryFromUserString};
 
 #[cfg_attr(feature = "python", pyo3::pyclass)]
 #[derive(
@@ -202,6 +204,100 @@ impl DSPItem {
         .contains(self)
     }
 
+    pub fn is_sorter(&self) -> bool {
+        [Self::SorterMKI, Self::SorterMKII, Self::SorterMKIII].contains(self)
+    }
+
+    pub fn is_splitter(&self) -> bool {
+        [Self::Splitter].contains(self)
+    }
+
+    pub fn is_piler(&self) -> bool {
+        [Self::AutomaticPiler].contains(self)
+    }
+
+    pub fn is_traffic_monitor(&self) -> bool {
+        [Self::TrafficMonitor].contains(self)
+    }
+
+    pub fn is_production(&self) -> bool {
+        [
+            Self::AssemblingMachineMkI,
+            Self::AssemblingMachineMkII,
+            Self::AssemblingMachineMkIII,
+            Self::RecomposingAssembler,
+            Self::ArcSmelter,
+            Self::PlaneSmelter,
+            Self::NegentropySmelter,
+            Self::ChemicalPlant,
+            Self::QuantumChemicalPlant,
+            Self::OilRefinery,
+            Self::MiniatureParticleCollider,
+        ]
+        .contains(self)
+    }
+
+    pub fn is_lab(&self) -> bool {
+        [Self::MatrixLab, Self::SelfevolutionLab].contains(self)
+    }
+
+    pub fn is_depot(&self) -> bool {
+        [Self::DepotMKI, Self::DepotMKII].contains(self)
+    }
+
+    pub fn is_tank(&self) -> bool {
+        [Self::StorageTank].contains(self)
+    }
+
+    pub fn is_distributor(&self) -> bool {
+        [Self::LogisticsDistributor].contains(self)
+    }
+
+    pub fn is_fuel_generator(&self) -> bool {
+        [
+            Self::ThermalPowerPlant,
+            Self::MiniFusionPowerPlant,
+            Self::ArtificialStar,
+        ]
+        .contains(self)
+    }
+
+    pub fn is_ray_receiver(&self) -> bool {
+        [Self::RayReceiver].contains(self)
+    }
+
+    pub fn is_energy_exchanger(&self) -> bool {
+        [Self::EnergyExchanger].contains(self)
+    }
+
+    pub fn is_accumulator(&self) -> bool {
+        [Self::Accumulator, Self::AccumulatorFull].contains(self)
+    }
+
+    pub fn is_turret(&self) -> bool {
+        [
+            Self::GaussTurret,
+            Self::LaserTurret,
+            Self::ImplosionCannon,
+            Self::PlasmaTurret,
+            Self::MissileTurret,
+        ]
+        .contains(self)
+    }
+
+    pub fn is_defense_tower(&self) -> bool {
+        [
+            Self::JammerTower,
+            Self::SignalTower,
+            Self::PlanetaryShieldGenerator,
+        ]
+        .contains(self)
+    }
+
+    pub fn is_battle_base(&self) -> bool {
+        [Self::BattlefieldAnalysisBase].contains(self)
+    }
+
     pub fn is_station(&self) -> bool {
         [
             Self::PlanetaryLogisticsStation,
@@ -213,6 +309,51 @@ impl DSPItem {
     pub fn is_interstellar_station(&self) -> bool {
         [Self::InterstellarLogisticsStation].contains(self)
     }
+
+    pub fn is_orbital_collector(&self) -> bool {
+        [Self::OrbitalCollector].contains(self)
+    }
+
+    pub fn is_ejector(&self) -> bool {
+        [Self::EMRailEjector].contains(self)
+    }
+
+    pub fn is_silo(&self) -> bool {
+        [Self::VerticalLaunchingSilo].contains(self)
+    }
+
+    /// Mined, pumped, collected from gas giants or dropped by the dark fog. Some of these have
+    /// recipes too, but are not usually made.
+    pub fn is_raw_resource(&self) -> bool {
+        [
+            Self::IronOre,
+            Self::CopperOre,
+            Self::SiliconOre,
+            Self::TitaniumOre,
+            Self::Stone,
+            Self::Coal,
+            Self::Log,
+            Self::PlantFuel,
+            Self::FireIce,
+            Self::KimberliteOre,
+            Self::FractalSilicon,
+            Self::GratingCrystal,
+            Self::StalagmiteCrystal,
+            Self::UnipolarMagnet,
+            Self::Water,
+            Self::CrudeOil,
+            Self::SulfuricAcid,
+            Self::Hydrogen,
+            Self::Deuterium,
+            Self::DarkFogMatrix,
+            Self::SiliconbasedNeuron,
+            Self::MatterRecombinator,
+            Self::NegentropySingularity,
+            Self::CoreElement,
+            Self::EnergyShard,
+        ]
+        .contains(self)
+    }
 }
 
 #[cfg(feature = "python")]
@@ -400,9 +541,16 @@ pub enum DSPRecipe {
 }
 
 impl DSPRecipe {
+    /// The recipe named like the item, or else the first one making it.
     pub fn for_item(item: &DSPItem) -> Option<Self> {
-        let foo: &str = item.as_ref();
-        Self::try_from(foo).ok()
+        let name: &str = item.as_ref();
+        Self::try_from(name).ok().or_else(|| {
+            Self::iter().find(|r| {
+                r.outputs()
+                    .and_then(|o| o.first())
+                    .is_some_and(|(i, _)| i == item)
+            })
+        })
     }
 }
 
@@ -495,18 +643,25 @@ impl BPModel {
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(EnumString, EnumIter, AsRefStr, Clone, Copy, PartialEq, Eq, Hash, Debug)]
 pub enum BuildingClass {
     Assembler,
     Smelter,
     Belt,
     Sorter,
+    Turret,
     Other,
 }
 
+impl TryFromUserString for BuildingClass {
+    fn try_from_user_string(s: &str) -> anyhow::Result<Self> {
+        try_from_user_string_listed(s, "building class")
+    }
+}
+
 impl BuildingClass {
     pub fn replacement_is_valid(i: DSPItem, o: DSPItem) -> bool {
-        return Self::from(i) == Self::from(o) && Self::from(i) != Self::Other;
+        Self::from(i) == Self::from(o) && Self::from(i) != Self::Other
     }
 }
 
@@ -524,6 +679,7 @@ impl From<DSPItem> for BuildingClass {
             DSPItem::ConveyorBeltMKIII => Self::Belt,
             DSPItem::ArcSmelter => Self::Smelter,
             DSPItem::PlaneSmelter => Self::Smelter,
+            i if i.is_turret() => Self::Turret,
             _ => Self::Other,
         }
     }
@@ -2,7 +2,17 @@ pub mod area;
 pub mod belt;
 pub mod blueprint;
 pub mod building;
+pub mod defense;
+pub mod dyson;
 pub mod enums;
+pub mod monitor;
+pub mod piler;
+pub mod power;
+pub mod production;
+pub mod recipe;
+pub mod sorter;
+pub mod splitter;
 pub mod station;
+pub mod storage;
 pub mod traits;
 pub mod visit;
@@ -0,0 +1,58 @@
+use binrw::{BinRead, BinWrite};
+#[cfg(feature = "dump")]
+use serde::{Deserialize, Serialize};
+
+use super::{
+    traits::{IconId, ItemId},
+    visit::{Visit, Visitor},
+};
+
+#[cfg_attr(feature = "dump", derive(Serialize, Deserialize))]
+#[derive(BinRead, BinWrite)]
+#[br(import(param_count: usize))]
+#[br(pre_assert(param_count >= 10))]
+pub struct TrafficMonitor {
+    #[br(little)]
+    pub target_cargo_amount: u32, // Items passing in one period
+    #[br(little)]
+    pub period_ticks: u32, // 60 ticks per second
+    #[br(little)]
+    pub pass_condition: u32, // 0 - none, 1 - equal, 2 - not equal, 3 - at least, 4 - more, 5 - at most, 6 - less
+    #[br(little)]
+    pub pass_color: u32,
+    #[br(little)]
+    pub fail_color: u32,
+    #[br(little)]
+    pub cargo_filter: ItemId<u32>, // 0 counts all items
+    #[br(little)]
+    pub spawn_mode: u32, // 0 - off, 1 - spawn cargo_filter items, 2 - consume them
+    #[br(little)]
+    pub alarm_mode: u32, // 0 - off, 1 - on fail, 2 - on pass
+    #[br(little)]
+    pub alarm_signal: IconId<u32>,
+    #[br(little)]
+    pub alarm_tone: u32,
+    #[br(count = param_count - 10)]
+    #[br(little)]
+    pub unknown: Vec<u32>,
+}
+
+impl TrafficMonitor {
+    pub const TICKS_PER_MINUTE: f64 = 3600.0;
+
+    pub fn flow_per_minute(&self) -> f64 {
+        if self.period_ticks == 0 {
+            return 0.0;
+        }
+        self.target_cargo_amount as f64 * Self::TICKS_PER_MINUTE / self.period_ticks as f64
+    }
+
+    pub fn set_flow_per_minute(&mut self, flow: f64) {
+        self.target_cargo_amount =
+            (flow * self.period_ticks as f64 / Self::TICKS_PER_MINUTE).round() as u32;
+    }
+}
+
+impl Visit for TrafficMonitor {
+    fn visit<T: Visitor + ?Sized>(&mut self, _visitor: &mut T) {}
+}
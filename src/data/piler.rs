@@ -0,0 +1,23 @@
+use binrw::{BinRead, BinWrite};
+#[cfg(feature = "dump")]
+use serde::{Deserialize, Serialize};
+
+use super::visit::{Visit, Visitor};
+
+#[cfg_attr(feature = "dump", derive(Serialize, Deserialize))]
+#[derive(BinRead, BinWrite)]
+#[br(import(param_count: usize))]
+#[br(pre_assert(param_count >= 2))]
+pub struct Piler {
+    #[br(little)]
+    pub stack_count: u32, // 0 uses the researched maximum
+    #[br(little)]
+    pub direction: u32, // 0 - stack, 1 - unstack
+    #[br(count = param_count - 2)]
+    #[br(little)]
+    pub unknown: Vec<u32>,
+}
+
+impl Visit for Piler {
+    fn visit<T: Visitor + ?Sized>(&mut self, _visitor: &mut T) {}
+}
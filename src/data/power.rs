@@ -0,0 +1,202 @@
+use binrw::{BinRead, BinWrite};
+#[cfg(feature = "dump")]
+use serde::{Deserialize, Serialize};
+use strum::{AsRefStr, EnumIter, EnumString};
+
+use super::{
+    enums::DSPItem,
+    traits::{try_from_user_string_listed, ItemId, TryFromUserString},
+    visit::{Visit, Visitor},
+};
+
+/// Power in watts a building draws while working and while idle, and what it generates at full
+/// output. Charging stations and distributors and exchangers depends on their settings and is
+/// not in here.
+#[derive(Default, Clone, Copy, PartialEq, Debug)]
+pub struct PowerUsage {
+    pub work: f64,
+    pub idle: f64,
+    pub generation: f64,
+}
+
+impl PowerUsage {
+    const fn draw(work: f64, idle: f64) -> Self {
+        Self {
+            work,
+            idle,
+            generation: 0.0,
+        }
+    }
+
+    const fn generator(generation: f64) -> Self {
+        Self {
+            work: 0.0,
+            idle: 0.0,
+            generation,
+        }
+    }
+
+    /// None for buildings whose power use isn't known here, like defense buildings.
+    pub fn of(i: DSPItem) -> Option<Self> {
+        const K: f64 = 1e3;
+        const M: f64 = 1e6;
+        let p = match i {
+            DSPItem::SorterMKI => Self::draw(18.0 * K, 9.0 * K),
+            DSPItem::SorterMKII => Self::draw(36.0 * K, 18.0 * K),
+            DSPItem::SorterMKIII => Self::draw(72.0 * K, 36.0 * K),
+            DSPItem::SprayCoater => Self::draw(90.0 * K, 9.0 * K),
+            DSPItem::AssemblingMachineMkI => Self::draw(270.0 * K, 12.0 * K),
+            DSPItem::AssemblingMachineMkII => Self::draw(480.0 * K, 15.0 * K),
+            DSPItem::AssemblingMachineMkIII => Self::draw(780.0 * K, 18.0 * K),
+            DSPItem::RecomposingAssembler => Self::draw(2.7 * M, 36.0 * K),
+            DSPItem::ArcSmelter => Self::draw(360.0 * K, 12.0 * K),
+            DSPItem::PlaneSmelter => Self::draw(1.44 * M, 48.0 * K),
+            DSPItem::NegentropySmelter => Self::draw(2.88 * M, 48.0 * K),
+            DSPItem::ChemicalPlant => Self::draw(720.0 * K, 24.0 * K),
+            DSPItem::QuantumChemicalPlant => Self::draw(2.16 * M, 48.0 * K),
+            DSPItem::OilRefinery => Self::draw(960.0 * K, 24.0 * K),
+            DSPItem::OilExtractor => Self::draw(840.0 * K, 24.0 * K),
+            DSPItem::MiningMachine => Self::draw(420.0 * K, 24.0 * K),
+            DSPItem::AdvancedMiningMachine => Self::draw(630.0 * K, 24.0 * K),
+            DSPItem::WaterPump => Self::draw(300.0 * K, 12.0 * K),
+            DSPItem::Fractionator => Self::draw(720.0 * K, 18.0 * K),
+            DSPItem::MiniatureParticleCollider => Self::draw(12.0 * M, 120.0 * K),
+            DSPItem::MatrixLab => Self::draw(480.0 * K, 12.0 * K),
+            DSPItem::SelfevolutionLab => Self::draw(1.44 * M, 36.0 * K),
+            DSPItem::EMRailEjector => Self::draw(3.0 * M, 24.0 * K),
+            DSPItem::VerticalLaunchingSilo => Self::draw(36.0 * M, 720.0 * K),
+            DSPItem::WindTurbine => Self::generator(300.0 * K),
+            DSPItem::SolarPanel => Self::generator(360.0 * K),
+            DSPItem::ThermalPowerPlant => Self::generator(2.16 * M),
+            DSPItem::GeothermalPowerStation => Self::generator(2.4 * M),
+            DSPItem::MiniFusionPowerPlant => Self::generator(15.0 * M),
+            DSPItem::ArtificialStar => Self::generator(72.0 * M),
+            DSPItem::RayReceiver => Self::generator(15.0 * M),
+            DSPItem::TeslaTower | DSPItem::SatelliteSubstation | DSPItem::OrbitalCollector => {
+                Self::default()
+            }
+            _ if i.is_belt()
+                || i.is_splitter()
+                || i.is_depot()
+                || i.is_tank()
+                || i.is_station()
+                || i.is_distributor()
+                || i.is_energy_exchanger() =>
+            {
+                Self::default()
+            }
+            _ => return None,
+        };
+        Some(p)
+    }
+}
+
+impl std::ops::AddAssign for PowerUsage {
+    fn add_assign(&mut self, o: Self) {
+        self.work += o.work;
+        self.idle += o.idle;
+        self.generation += o.generation;
+    }
+}
+
+/// What an energy exchanger moves in or out of the grid.
+pub const EXCHANGER_POWER: f64 = 45e6;
+
+// Thermal and fusion power plants, artificial stars.
+#[cfg_attr(feature = "dump", derive(Serialize, Deserialize))]
+#[derive(BinRead, BinWrite)]
+#[br(import(param_count: usize))]
+#[br(pre_assert(param_count >= 1))]
+pub struct FuelGenerator {
+    #[br(little)]
+    pub fuel: ItemId<u32>, // 0 if not set
+    #[br(count = param_count - 1)]
+    #[br(little)]
+    pub unknown: Vec<u32>,
+}
+
+impl Visit for FuelGenerator {
+    fn visit<T: Visitor + ?Sized>(&mut self, _visitor: &mut T) {}
+}
+
+#[cfg_attr(feature = "dump", derive(Serialize, Deserialize))]
+#[derive(BinRead, BinWrite, EnumString, EnumIter, AsRefStr, PartialEq, Eq, Clone, Copy, Debug)]
+#[brw(repr = u32)]
+pub enum RayReceiverMode {
+    #[strum(serialize = "power")]
+    Power = 0,
+    #[strum(serialize = "photon")]
+    Photon = 1,
+}
+
+impl TryFromUserString for RayReceiverMode {
+    fn try_from_user_string(s: &str) -> anyhow::Result<Self> {
+        try_from_user_string_listed(s, "ray receiver mode")
+    }
+}
+
+#[cfg_attr(feature = "dump", derive(Serialize, Deserialize))]
+#[derive(BinRead, BinWrite)]
+#[br(import(param_count: usize))]
+#[br(pre_assert(param_count >= 2))]
+pub struct RayReceiver {
+    pub mode: RayReceiverMode,
+    #[br(little)]
+    pub lens: ItemId<u32>, // 0 if no graviton lens is used
+    #[br(count = param_count - 2)]
+    #[br(little)]
+    pub unknown: Vec<u32>,
+}
+
+impl Visit for RayReceiver {
+    fn visit<T: Visitor + ?Sized>(&mut self, _visitor: &mut T) {}
+}
+
+// The game keeps this as a signed -1/0/1.
+#[cfg_attr(feature = "dump", derive(Serialize, Deserialize))]
+#[derive(BinRead, BinWrite, EnumString, EnumIter, AsRefStr, PartialEq, Eq, Clone, Copy, Debug)]
+#[brw(repr = u32)]
+#[repr(u32)]
+pub enum ExchangerMode {
+    #[strum(serialize = "discharge")]
+    Discharge = 0xFFFF_FFFF,
+    #[strum(serialize = "standby")]
+    Standby = 0,
+    #[strum(serialize = "charge")]
+    Charge = 1,
+}
+
+impl TryFromUserString for ExchangerMode {
+    fn try_from_user_string(s: &str) -> anyhow::Result<Self> {
+        try_from_user_string_listed(s, "energy exchanger mode")
+    }
+}
+
+#[cfg_attr(feature = "dump", derive(Serialize, Deserialize))]
+#[derive(BinRead, BinWrite)]
+#[br(import(param_count: usize))]
+#[br(pre_assert(param_count >= 1))]
+pub struct EnergyExchanger {
+    pub mode: ExchangerMode,
+    #[br(count = param_count - 1)]
+    #[br(little)]
+    pub unknown: Vec<u32>,
+}
+
+impl Visit for EnergyExchanger {
+    fn visit<T: Visitor + ?Sized>(&mut self, _visitor: &mut T) {}
+}
+
+// No settings of note, kept raw so dumps at least show what this is.
+#[cfg_attr(feature = "dump", derive(Serialize, Deserialize))]
+#[derive(BinRead, BinWrite)]
+#[br(import(param_count: usize))]
+pub struct Accumulator {
+    #[br(count = param_count)]
+    #[br(little)]
+    pub unknown: Vec<u32>,
+}
+
+impl Visit for Accumulator {
+    fn visit<T: Visitor + ?Sized>(&mut self, _visitor: &mut T) {}
+}
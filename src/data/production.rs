@@ -0,0 +1,99 @@
+use binrw::{BinRead, BinWrite};
+#[cfg(feature = "dump")]
+use serde::{Deserialize, Serialize};
+use strum::{AsRefStr, EnumIter, EnumString};
+
+use super::{
+    traits::{try_from_user_string_listed, TryFromUserString},
+    visit::{Visit, Visitor},
+};
+
+#[cfg_attr(feature = "dump", derive(Serialize, Deserialize))]
+#[derive(BinRead, BinWrite, EnumString, EnumIter, AsRefStr, PartialEq, Eq, Clone, Copy, Debug)]
+#[brw(repr = u32)]
+pub enum ProliferatorMode {
+    #[strum(serialize = "extra-products")]
+    ExtraProducts = 0,
+    #[strum(serialize = "speedup")]
+    Speedup = 1,
+}
+
+impl TryFromUserString for ProliferatorMode {
+    fn try_from_user_string(s: &str) -> anyhow::Result<Self> {
+        try_from_user_string_listed(s, "proliferator mode")
+    }
+}
+
+/// Proliferator sprayed on ingredients. Blueprints don't say which one will be used.
+#[derive(EnumString, EnumIter, AsRefStr, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Proliferator {
+    #[strum(serialize = "none")]
+    None,
+    #[strum(serialize = "mk1")]
+    MkI,
+    #[strum(serialize = "mk2")]
+    MkII,
+    #[strum(serialize = "mk3")]
+    MkIII,
+}
+
+impl Proliferator {
+    /// Extra crafting speed in speedup mode, 1.0 being twice as fast.
+    pub fn speedup(&self) -> f64 {
+        match self {
+            Self::None => 0.0,
+            Self::MkI => 0.25,
+            Self::MkII => 0.5,
+            Self::MkIII => 1.0,
+        }
+    }
+
+    /// Extra products in extra products mode, as a fraction of the normal output.
+    pub fn extra_products(&self) -> f64 {
+        match self {
+            Self::None => 0.0,
+            Self::MkI => 0.125,
+            Self::MkII => 0.2,
+            Self::MkIII => 0.25,
+        }
+    }
+}
+
+impl TryFromUserString for Proliferator {
+    fn try_from_user_string(s: &str) -> anyhow::Result<Self> {
+        try_from_user_string_listed(s, "proliferator")
+    }
+}
+
+// Assemblers, smelters, chemical plants, refineries, colliders.
+#[cfg_attr(feature = "dump", derive(Serialize, Deserialize))]
+#[derive(BinRead, BinWrite)]
+#[br(import(param_count: usize))]
+#[br(pre_assert(param_count >= 1))]
+pub struct ProductionParams {
+    pub proliferator_mode: ProliferatorMode,
+    #[br(count = param_count - 1)]
+    #[br(little)]
+    pub unknown: Vec<u32>,
+}
+
+impl Visit for ProductionParams {
+    fn visit<T: Visitor + ?Sized>(&mut self, _visitor: &mut T) {}
+}
+
+#[cfg_attr(feature = "dump", derive(Serialize, Deserialize))]
+#[derive(BinRead, BinWrite)]
+#[br(import(param_count: usize))]
+#[br(pre_assert(param_count >= 2))]
+pub struct LabParams {
+    #[br(little)]
+    pub research_mode: u32, // 0 - production, 1 - research
+    pub proliferator_mode: ProliferatorMode,
+    #[br(count = param_count - 2)]
+    #[br(little)]
+    pub unknown: Vec<u32>,
+}
+
+impl Visit for LabParams {
+    fn visit<T: Visitor + ?Sized>(&mut self, _visitor: &mut T) {}
+}
@@ -0,0 +1,1250 @@
+&[
+    RecipeInfo {
+        recipe: DSPRecipe::IronIngot,
+        facility: Facility::Smelter,
+        time: 1.0,
+        extra_products: true,
+        inputs: &[(DSPItem::IronOre, 1)],
+        outputs: &[(DSPItem::IronIngot, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::Magnet,
+        facility: Facility::Smelter,
+        time: 1.5,
+        extra_products: true,
+        inputs: &[(DSPItem::IronOre, 1)],
+        outputs: &[(DSPItem::Magnet, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::CopperIngot,
+        facility: Facility::Smelter,
+        time: 1.0,
+        extra_products: true,
+        inputs: &[(DSPItem::CopperOre, 1)],
+        outputs: &[(DSPItem::CopperIngot, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::StoneBrick,
+        facility: Facility::Smelter,
+        time: 1.0,
+        extra_products: true,
+        inputs: &[(DSPItem::Stone, 1)],
+        outputs: &[(DSPItem::StoneBrick, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::Gear,
+        facility: Facility::Assembler,
+        time: 1.0,
+        extra_products: true,
+        inputs: &[(DSPItem::IronIngot, 1)],
+        outputs: &[(DSPItem::Gear, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::MagneticCoil,
+        facility: Facility::Assembler,
+        time: 1.0,
+        extra_products: true,
+        inputs: &[(DSPItem::Magnet, 2), (DSPItem::CopperIngot, 1)],
+        outputs: &[(DSPItem::MagneticCoil, 2)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::WindTurbine,
+        facility: Facility::Assembler,
+        time: 4.0,
+        extra_products: false,
+        inputs: &[(DSPItem::IronIngot, 6), (DSPItem::Gear, 1), (DSPItem::MagneticCoil, 3)],
+        outputs: &[(DSPItem::WindTurbine, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::TeslaTower,
+        facility: Facility::Assembler,
+        time: 1.0,
+        extra_products: false,
+        inputs: &[(DSPItem::IronIngot, 2), (DSPItem::MagneticCoil, 1)],
+        outputs: &[(DSPItem::TeslaTower, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::ElectromagneticMatrix,
+        facility: Facility::Lab,
+        time: 3.0,
+        extra_products: true,
+        inputs: &[(DSPItem::MagneticCoil, 1), (DSPItem::CircuitBoard, 1)],
+        outputs: &[(DSPItem::ElectromagneticMatrix, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::MatrixLab,
+        facility: Facility::Assembler,
+        time: 3.0,
+        extra_products: false,
+        inputs: &[(DSPItem::IronIngot, 8), (DSPItem::Glass, 4), (DSPItem::CircuitBoard, 4), (DSPItem::MagneticCoil, 4)],
+        outputs: &[(DSPItem::MatrixLab, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::Prism,
+        facility: Facility::Assembler,
+        time: 2.0,
+        extra_products: true,
+        inputs: &[(DSPItem::Glass, 3)],
+        outputs: &[(DSPItem::Prism, 2)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::PlasmaExciter,
+        facility: Facility::Assembler,
+        time: 2.0,
+        extra_products: true,
+        inputs: &[(DSPItem::MagneticCoil, 4), (DSPItem::Prism, 2)],
+        outputs: &[(DSPItem::PlasmaExciter, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::WirelessPowerTower,
+        facility: Facility::Assembler,
+        time: 3.0,
+        extra_products: false,
+        inputs: &[(DSPItem::TeslaTower, 1), (DSPItem::PlasmaExciter, 3)],
+        outputs: &[(DSPItem::WirelessPowerTower, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::OilExtractor,
+        facility: Facility::Assembler,
+        time: 8.0,
+        extra_products: false,
+        inputs: &[(DSPItem::Steel, 12), (DSPItem::StoneBrick, 12), (DSPItem::CircuitBoard, 6), (DSPItem::PlasmaExciter, 4)],
+        outputs: &[(DSPItem::OilExtractor, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::OilRefinery,
+        facility: Facility::Assembler,
+        time: 6.0,
+        extra_products: false,
+        inputs: &[(DSPItem::Steel, 10), (DSPItem::StoneBrick, 10), (DSPItem::CircuitBoard, 6), (DSPItem::PlasmaExciter, 6)],
+        outputs: &[(DSPItem::OilRefinery, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::PlasmaRefining,
+        facility: Facility::Refinery,
+        time: 4.0,
+        extra_products: true,
+        inputs: &[(DSPItem::CrudeOil, 2)],
+        outputs: &[(DSPItem::Hydrogen, 1), (DSPItem::RefinedOil, 2)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::EnergeticGraphite,
+        facility: Facility::Smelter,
+        time: 2.0,
+        extra_products: true,
+        inputs: &[(DSPItem::Coal, 2)],
+        outputs: &[(DSPItem::EnergeticGraphite, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::EnergyMatrix,
+        facility: Facility::Lab,
+        time: 6.0,
+        extra_products: true,
+        inputs: &[(DSPItem::EnergeticGraphite, 2), (DSPItem::Hydrogen, 2)],
+        outputs: &[(DSPItem::EnergyMatrix, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::HydrogenFuelRod,
+        facility: Facility::Assembler,
+        time: 6.0,
+        extra_products: true,
+        inputs: &[(DSPItem::TitaniumIngot, 1), (DSPItem::Hydrogen, 10)],
+        outputs: &[(DSPItem::HydrogenFuelRod, 2)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::Thruster,
+        facility: Facility::Assembler,
+        time: 4.0,
+        extra_products: true,
+        inputs: &[(DSPItem::Steel, 2), (DSPItem::CopperIngot, 3)],
+        outputs: &[(DSPItem::Thruster, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::ReinforcedThruster,
+        facility: Facility::Assembler,
+        time: 6.0,
+        extra_products: true,
+        inputs: &[(DSPItem::TitaniumAlloy, 5), (DSPItem::ElectromagneticTurbine, 5)],
+        outputs: &[(DSPItem::ReinforcedThruster, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::ChemicalPlant,
+        facility: Facility::Assembler,
+        time: 5.0,
+        extra_products: false,
+        inputs: &[(DSPItem::Steel, 8), (DSPItem::StoneBrick, 8), (DSPItem::Glass, 8), (DSPItem::CircuitBoard, 2)],
+        outputs: &[(DSPItem::ChemicalPlant, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::Plastic,
+        facility: Facility::ChemicalPlant,
+        time: 3.0,
+        extra_products: true,
+        inputs: &[(DSPItem::RefinedOil, 2), (DSPItem::EnergeticGraphite, 1)],
+        outputs: &[(DSPItem::Plastic, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::SulfuricAcid,
+        facility: Facility::ChemicalPlant,
+        time: 6.0,
+        extra_products: true,
+        inputs: &[(DSPItem::RefinedOil, 6), (DSPItem::Stone, 8), (DSPItem::Water, 4)],
+        outputs: &[(DSPItem::SulfuricAcid, 4)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::OrganicCrystal,
+        facility: Facility::ChemicalPlant,
+        time: 6.0,
+        extra_products: true,
+        inputs: &[(DSPItem::Plastic, 2), (DSPItem::RefinedOil, 1), (DSPItem::Water, 1)],
+        outputs: &[(DSPItem::OrganicCrystal, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::TitaniumCrystal,
+        facility: Facility::Assembler,
+        time: 4.0,
+        extra_products: true,
+        inputs: &[(DSPItem::OrganicCrystal, 1), (DSPItem::TitaniumIngot, 3)],
+        outputs: &[(DSPItem::TitaniumCrystal, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::StructureMatrix,
+        facility: Facility::Lab,
+        time: 8.0,
+        extra_products: true,
+        inputs: &[(DSPItem::Diamond, 1), (DSPItem::TitaniumCrystal, 1)],
+        outputs: &[(DSPItem::StructureMatrix, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::CasimirCrystal,
+        facility: Facility::Assembler,
+        time: 4.0,
+        extra_products: true,
+        inputs: &[(DSPItem::TitaniumCrystal, 1), (DSPItem::Graphene, 2), (DSPItem::Hydrogen, 12)],
+        outputs: &[(DSPItem::CasimirCrystal, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::CasimirCrystalAdvanced,
+        facility: Facility::Assembler,
+        time: 4.0,
+        extra_products: true,
+        inputs: &[(DSPItem::GratingCrystal, 8), (DSPItem::Graphene, 2), (DSPItem::Hydrogen, 12)],
+        outputs: &[(DSPItem::CasimirCrystal, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::TitaniumGlass,
+        facility: Facility::Assembler,
+        time: 5.0,
+        extra_products: true,
+        inputs: &[(DSPItem::Glass, 2), (DSPItem::TitaniumIngot, 2), (DSPItem::Water, 2)],
+        outputs: &[(DSPItem::TitaniumGlass, 2)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::Graphene,
+        facility: Facility::ChemicalPlant,
+        time: 3.0,
+        extra_products: true,
+        inputs: &[(DSPItem::EnergeticGraphite, 3), (DSPItem::SulfuricAcid, 1)],
+        outputs: &[(DSPItem::Graphene, 2)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::GrapheneAdvanced,
+        facility: Facility::ChemicalPlant,
+        time: 2.0,
+        extra_products: true,
+        inputs: &[(DSPItem::FireIce, 2)],
+        outputs: &[(DSPItem::Graphene, 2), (DSPItem::Hydrogen, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::CarbonNanotube,
+        facility: Facility::ChemicalPlant,
+        time: 4.0,
+        extra_products: true,
+        inputs: &[(DSPItem::Graphene, 3), (DSPItem::TitaniumIngot, 1)],
+        outputs: &[(DSPItem::CarbonNanotube, 2)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::SiliconOre,
+        facility: Facility::Smelter,
+        time: 10.0,
+        extra_products: true,
+        inputs: &[(DSPItem::Stone, 10)],
+        outputs: &[(DSPItem::SiliconOre, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::CarbonNanotubeAdvanced,
+        facility: Facility::ChemicalPlant,
+        time: 4.0,
+        extra_products: true,
+        inputs: &[(DSPItem::StalagmiteCrystal, 6)],
+        outputs: &[(DSPItem::CarbonNanotube, 2)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::ParticleBroadband,
+        facility: Facility::Assembler,
+        time: 8.0,
+        extra_products: true,
+        inputs: &[(DSPItem::CarbonNanotube, 2), (DSPItem::CrystalSilicon, 2), (DSPItem::Plastic, 1)],
+        outputs: &[(DSPItem::ParticleBroadband, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::CrystalSilicon,
+        facility: Facility::Smelter,
+        time: 2.0,
+        extra_products: true,
+        inputs: &[(DSPItem::HighpuritySilicon, 1)],
+        outputs: &[(DSPItem::CrystalSilicon, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::PlaneFilter,
+        facility: Facility::Assembler,
+        time: 12.0,
+        extra_products: true,
+        inputs: &[(DSPItem::CasimirCrystal, 1), (DSPItem::TitaniumGlass, 2)],
+        outputs: &[(DSPItem::PlaneFilter, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::MiniatureParticleCollider,
+        facility: Facility::Assembler,
+        time: 15.0,
+        extra_products: false,
+        inputs: &[(DSPItem::TitaniumAlloy, 20), (DSPItem::FrameMaterial, 20), (DSPItem::SupermagneticRing, 25), (DSPItem::Graphene, 10), (DSPItem::Processor, 8)],
+        outputs: &[(DSPItem::MiniatureParticleCollider, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::Deuterium,
+        facility: Facility::Collider,
+        time: 2.5,
+        extra_products: true,
+        inputs: &[(DSPItem::Hydrogen, 10)],
+        outputs: &[(DSPItem::Deuterium, 5)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::DeuteronFuelRod,
+        facility: Facility::Assembler,
+        time: 12.0,
+        extra_products: true,
+        inputs: &[(DSPItem::TitaniumAlloy, 1), (DSPItem::Deuterium, 20), (DSPItem::SupermagneticRing, 1)],
+        outputs: &[(DSPItem::DeuteronFuelRod, 2)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::AnnihilationConstraintSphere,
+        facility: Facility::Assembler,
+        time: 20.0,
+        extra_products: true,
+        inputs: &[(DSPItem::ParticleContainer, 1), (DSPItem::Processor, 1)],
+        outputs: &[(DSPItem::AnnihilationConstraintSphere, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::ArtificialStar,
+        facility: Facility::Assembler,
+        time: 30.0,
+        extra_products: false,
+        inputs: &[(DSPItem::TitaniumAlloy, 20), (DSPItem::FrameMaterial, 20), (DSPItem::AnnihilationConstraintSphere, 10), (DSPItem::QuantumChip, 10)],
+        outputs: &[(DSPItem::ArtificialStar, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::AntimatterFuelRod,
+        facility: Facility::Assembler,
+        time: 24.0,
+        extra_products: true,
+        inputs: &[(DSPItem::Antimatter, 12), (DSPItem::Hydrogen, 12), (DSPItem::AnnihilationConstraintSphere, 1), (DSPItem::TitaniumAlloy, 1)],
+        outputs: &[(DSPItem::AntimatterFuelRod, 2)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::AssemblingMachineMkI,
+        facility: Facility::Assembler,
+        time: 2.0,
+        extra_products: false,
+        inputs: &[(DSPItem::IronIngot, 4), (DSPItem::Gear, 8), (DSPItem::CircuitBoard, 4)],
+        outputs: &[(DSPItem::AssemblingMachineMkI, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::AssemblingMachineMkII,
+        facility: Facility::Assembler,
+        time: 3.0,
+        extra_products: false,
+        inputs: &[(DSPItem::AssemblingMachineMkI, 1), (DSPItem::Graphene, 8), (DSPItem::Processor, 4)],
+        outputs: &[(DSPItem::AssemblingMachineMkII, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::AssemblingMachineMkIII,
+        facility: Facility::Assembler,
+        time: 4.0,
+        extra_products: false,
+        inputs: &[(DSPItem::AssemblingMachineMkII, 1), (DSPItem::ParticleBroadband, 8), (DSPItem::QuantumChip, 2)],
+        outputs: &[(DSPItem::AssemblingMachineMkIII, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::MiningMachine,
+        facility: Facility::Assembler,
+        time: 3.0,
+        extra_products: false,
+        inputs: &[(DSPItem::IronIngot, 4), (DSPItem::CircuitBoard, 2), (DSPItem::MagneticCoil, 2), (DSPItem::Gear, 2)],
+        outputs: &[(DSPItem::MiningMachine, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::WaterPump,
+        facility: Facility::Assembler,
+        time: 4.0,
+        extra_products: false,
+        inputs: &[(DSPItem::IronIngot, 8), (DSPItem::StoneBrick, 4), (DSPItem::ElectricMotor, 4), (DSPItem::CircuitBoard, 2)],
+        outputs: &[(DSPItem::WaterPump, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::CircuitBoard,
+        facility: Facility::Assembler,
+        time: 1.0,
+        extra_products: true,
+        inputs: &[(DSPItem::IronIngot, 2), (DSPItem::CopperIngot, 1)],
+        outputs: &[(DSPItem::CircuitBoard, 2)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::Processor,
+        facility: Facility::Assembler,
+        time: 3.0,
+        extra_products: true,
+        inputs: &[(DSPItem::CircuitBoard, 2), (DSPItem::MicrocrystallineComponent, 2)],
+        outputs: &[(DSPItem::Processor, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::QuantumChip,
+        facility: Facility::Assembler,
+        time: 6.0,
+        extra_products: true,
+        inputs: &[(DSPItem::Processor, 2), (DSPItem::PlaneFilter, 2)],
+        outputs: &[(DSPItem::QuantumChip, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::MicrocrystallineComponent,
+        facility: Facility::Assembler,
+        time: 2.0,
+        extra_products: true,
+        inputs: &[(DSPItem::HighpuritySilicon, 2), (DSPItem::CopperIngot, 1)],
+        outputs: &[(DSPItem::MicrocrystallineComponent, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::OrganicCrystalOriginal,
+        facility: Facility::Assembler,
+        time: 6.0,
+        extra_products: true,
+        inputs: &[(DSPItem::Log, 20), (DSPItem::PlantFuel, 30), (DSPItem::Water, 10)],
+        outputs: &[(DSPItem::OrganicCrystal, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::InformationMatrix,
+        facility: Facility::Lab,
+        time: 10.0,
+        extra_products: true,
+        inputs: &[(DSPItem::Processor, 2), (DSPItem::ParticleBroadband, 1)],
+        outputs: &[(DSPItem::InformationMatrix, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::ArcSmelter,
+        facility: Facility::Assembler,
+        time: 3.0,
+        extra_products: false,
+        inputs: &[(DSPItem::IronIngot, 4), (DSPItem::StoneBrick, 2), (DSPItem::CircuitBoard, 4), (DSPItem::MagneticCoil, 2)],
+        outputs: &[(DSPItem::ArcSmelter, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::Glass,
+        facility: Facility::Smelter,
+        time: 2.0,
+        extra_products: true,
+        inputs: &[(DSPItem::Stone, 2)],
+        outputs: &[(DSPItem::Glass, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::XrayCracking,
+        facility: Facility::Refinery,
+        time: 4.0,
+        extra_products: false,
+        inputs: &[(DSPItem::RefinedOil, 1), (DSPItem::Hydrogen, 2)],
+        outputs: &[(DSPItem::Hydrogen, 3), (DSPItem::EnergeticGraphite, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::HighpuritySilicon,
+        facility: Facility::Smelter,
+        time: 2.0,
+        extra_products: true,
+        inputs: &[(DSPItem::SiliconOre, 2)],
+        outputs: &[(DSPItem::HighpuritySilicon, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::Diamond,
+        facility: Facility::Smelter,
+        time: 2.0,
+        extra_products: true,
+        inputs: &[(DSPItem::EnergeticGraphite, 1)],
+        outputs: &[(DSPItem::Diamond, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::DiamondAdvanced,
+        facility: Facility::Smelter,
+        time: 1.5,
+        extra_products: true,
+        inputs: &[(DSPItem::KimberliteOre, 1)],
+        outputs: &[(DSPItem::Diamond, 2)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::CrystalSiliconAdvanced,
+        facility: Facility::Assembler,
+        time: 1.5,
+        extra_products: true,
+        inputs: &[(DSPItem::FractalSilicon, 1)],
+        outputs: &[(DSPItem::CrystalSilicon, 2)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::Steel,
+        facility: Facility::Smelter,
+        time: 3.0,
+        extra_products: true,
+        inputs: &[(DSPItem::IronIngot, 3)],
+        outputs: &[(DSPItem::Steel, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::ThermalPowerPlant,
+        facility: Facility::Assembler,
+        time: 5.0,
+        extra_products: false,
+        inputs: &[(DSPItem::IronIngot, 10), (DSPItem::StoneBrick, 4), (DSPItem::Gear, 4), (DSPItem::MagneticCoil, 4)],
+        outputs: &[(DSPItem::ThermalPowerPlant, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::TitaniumIngot,
+        facility: Facility::Smelter,
+        time: 2.0,
+        extra_products: true,
+        inputs: &[(DSPItem::TitaniumOre, 2)],
+        outputs: &[(DSPItem::TitaniumIngot, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::TitaniumAlloy,
+        facility: Facility::Smelter,
+        time: 12.0,
+        extra_products: true,
+        inputs: &[(DSPItem::TitaniumIngot, 4), (DSPItem::Steel, 4), (DSPItem::SulfuricAcid, 8)],
+        outputs: &[(DSPItem::TitaniumAlloy, 4)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::SolarPanel,
+        facility: Facility::Assembler,
+        time: 6.0,
+        extra_products: false,
+        inputs: &[(DSPItem::CopperIngot, 10), (DSPItem::HighpuritySilicon, 10), (DSPItem::CircuitBoard, 5)],
+        outputs: &[(DSPItem::SolarPanel, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::PhotonCombiner,
+        facility: Facility::Assembler,
+        time: 3.0,
+        extra_products: true,
+        inputs: &[(DSPItem::Prism, 2), (DSPItem::CircuitBoard, 1)],
+        outputs: &[(DSPItem::PhotonCombiner, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::PhotonCombinerAdvanced,
+        facility: Facility::Assembler,
+        time: 3.0,
+        extra_products: true,
+        inputs: &[(DSPItem::GratingCrystal, 1), (DSPItem::CircuitBoard, 1)],
+        outputs: &[(DSPItem::PhotonCombiner, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::SolarSail,
+        facility: Facility::Assembler,
+        time: 4.0,
+        extra_products: true,
+        inputs: &[(DSPItem::Graphene, 1), (DSPItem::PhotonCombiner, 1)],
+        outputs: &[(DSPItem::SolarSail, 2)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::EMRailEjector,
+        facility: Facility::Assembler,
+        time: 6.0,
+        extra_products: false,
+        inputs: &[(DSPItem::Steel, 20), (DSPItem::Gear, 20), (DSPItem::Processor, 5), (DSPItem::SupermagneticRing, 10)],
+        outputs: &[(DSPItem::EMRailEjector, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::RayReceiver,
+        facility: Facility::Assembler,
+        time: 8.0,
+        extra_products: false,
+        inputs: &[(DSPItem::Steel, 20), (DSPItem::HighpuritySilicon, 20), (DSPItem::PhotonCombiner, 10), (DSPItem::Processor, 5), (DSPItem::SupermagneticRing, 20)],
+        outputs: &[(DSPItem::RayReceiver, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::SatelliteSubstation,
+        facility: Facility::Assembler,
+        time: 5.0,
+        extra_products: false,
+        inputs: &[(DSPItem::WirelessPowerTower, 1), (DSPItem::SupermagneticRing, 10), (DSPItem::FrameMaterial, 2)],
+        outputs: &[(DSPItem::SatelliteSubstation, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::MassenergyStorage,
+        facility: Facility::Collider,
+        time: 2.0,
+        extra_products: false,
+        inputs: &[(DSPItem::CriticalPhoton, 2)],
+        outputs: &[(DSPItem::Antimatter, 2), (DSPItem::Hydrogen, 2)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::UniverseMatrix,
+        facility: Facility::Lab,
+        time: 15.0,
+        extra_products: true,
+        inputs: &[(DSPItem::ElectromagneticMatrix, 1), (DSPItem::EnergyMatrix, 1), (DSPItem::StructureMatrix, 1), (DSPItem::InformationMatrix, 1), (DSPItem::GravityMatrix, 1), (DSPItem::Antimatter, 1)],
+        outputs: &[(DSPItem::UniverseMatrix, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::Accumulator,
+        facility: Facility::Assembler,
+        time: 5.0,
+        extra_products: false,
+        inputs: &[(DSPItem::IronIngot, 6), (DSPItem::SupermagneticRing, 1), (DSPItem::CrystalSilicon, 6)],
+        outputs: &[(DSPItem::Accumulator, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::EnergyExchanger,
+        facility: Facility::Assembler,
+        time: 15.0,
+        extra_products: false,
+        inputs: &[(DSPItem::TitaniumAlloy, 40), (DSPItem::Steel, 40), (DSPItem::Processor, 40), (DSPItem::ParticleContainer, 8)],
+        outputs: &[(DSPItem::EnergyExchanger, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::SpaceWarper,
+        facility: Facility::Assembler,
+        time: 10.0,
+        extra_products: true,
+        inputs: &[(DSPItem::GravitonLens, 1)],
+        outputs: &[(DSPItem::SpaceWarper, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::SpaceWarperAdvanced,
+        facility: Facility::Assembler,
+        time: 10.0,
+        extra_products: true,
+        inputs: &[(DSPItem::GravityMatrix, 1)],
+        outputs: &[(DSPItem::SpaceWarper, 8)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::FrameMaterial,
+        facility: Facility::Assembler,
+        time: 6.0,
+        extra_products: true,
+        inputs: &[(DSPItem::CarbonNanotube, 4), (DSPItem::TitaniumAlloy, 1), (DSPItem::HighpuritySilicon, 1)],
+        outputs: &[(DSPItem::FrameMaterial, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::DysonSphereComponent,
+        facility: Facility::Assembler,
+        time: 8.0,
+        extra_products: true,
+        inputs: &[(DSPItem::FrameMaterial, 3), (DSPItem::SolarSail, 3), (DSPItem::Processor, 3)],
+        outputs: &[(DSPItem::DysonSphereComponent, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::VerticalLaunchingSilo,
+        facility: Facility::Assembler,
+        time: 30.0,
+        extra_products: false,
+        inputs: &[(DSPItem::TitaniumAlloy, 80), (DSPItem::FrameMaterial, 30), (DSPItem::GravitonLens, 20), (DSPItem::QuantumChip, 10)],
+        outputs: &[(DSPItem::VerticalLaunchingSilo, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::SmallCarrierRocket,
+        facility: Facility::Assembler,
+        time: 6.0,
+        extra_products: true,
+        inputs: &[(DSPItem::DysonSphereComponent, 2), (DSPItem::DeuteronFuelRod, 4), (DSPItem::QuantumChip, 2)],
+        outputs: &[(DSPItem::SmallCarrierRocket, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::ConveyorBeltMKI,
+        facility: Facility::Assembler,
+        time: 1.0,
+        extra_products: false,
+        inputs: &[(DSPItem::IronIngot, 2), (DSPItem::Gear, 1)],
+        outputs: &[(DSPItem::ConveyorBeltMKI, 3)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::SorterMKI,
+        facility: Facility::Assembler,
+        time: 1.0,
+        extra_products: false,
+        inputs: &[(DSPItem::IronIngot, 1), (DSPItem::CircuitBoard, 1)],
+        outputs: &[(DSPItem::SorterMKI, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::DepotMKI,
+        facility: Facility::Assembler,
+        time: 2.0,
+        extra_products: false,
+        inputs: &[(DSPItem::IronIngot, 4), (DSPItem::StoneBrick, 4)],
+        outputs: &[(DSPItem::DepotMKI, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::Splitter,
+        facility: Facility::Assembler,
+        time: 2.0,
+        extra_products: false,
+        inputs: &[(DSPItem::IronIngot, 3), (DSPItem::Gear, 2), (DSPItem::CircuitBoard, 1)],
+        outputs: &[(DSPItem::Splitter, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::SorterMKII,
+        facility: Facility::Assembler,
+        time: 1.0,
+        extra_products: false,
+        inputs: &[(DSPItem::SorterMKI, 2), (DSPItem::ElectricMotor, 1)],
+        outputs: &[(DSPItem::SorterMKII, 2)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::ConveyorBeltMKII,
+        facility: Facility::Assembler,
+        time: 1.0,
+        extra_products: false,
+        inputs: &[(DSPItem::ConveyorBeltMKI, 3), (DSPItem::ElectromagneticTurbine, 1)],
+        outputs: &[(DSPItem::ConveyorBeltMKII, 3)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::SorterMKIII,
+        facility: Facility::Assembler,
+        time: 1.0,
+        extra_products: false,
+        inputs: &[(DSPItem::SorterMKII, 2), (DSPItem::ElectromagneticTurbine, 1)],
+        outputs: &[(DSPItem::SorterMKIII, 2)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::DepotMKII,
+        facility: Facility::Assembler,
+        time: 4.0,
+        extra_products: false,
+        inputs: &[(DSPItem::Steel, 8), (DSPItem::StoneBrick, 8)],
+        outputs: &[(DSPItem::DepotMKII, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::ConveyorBeltMKIII,
+        facility: Facility::Assembler,
+        time: 1.0,
+        extra_products: false,
+        inputs: &[(DSPItem::ConveyorBeltMKII, 3), (DSPItem::SupermagneticRing, 1), (DSPItem::Graphene, 1)],
+        outputs: &[(DSPItem::ConveyorBeltMKIII, 3)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::PlanetaryLogisticsStation,
+        facility: Facility::Assembler,
+        time: 20.0,
+        extra_products: false,
+        inputs: &[(DSPItem::Steel, 40), (DSPItem::TitaniumIngot, 40), (DSPItem::Processor, 40), (DSPItem::ParticleContainer, 20)],
+        outputs: &[(DSPItem::PlanetaryLogisticsStation, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::LogisticsDrone,
+        facility: Facility::Assembler,
+        time: 4.0,
+        extra_products: false,
+        inputs: &[(DSPItem::IronIngot, 5), (DSPItem::Processor, 2), (DSPItem::Thruster, 2)],
+        outputs: &[(DSPItem::LogisticsDrone, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::InterstellarLogisticsStation,
+        facility: Facility::Assembler,
+        time: 30.0,
+        extra_products: false,
+        inputs: &[(DSPItem::PlanetaryLogisticsStation, 1), (DSPItem::TitaniumAlloy, 40), (DSPItem::ParticleContainer, 20)],
+        outputs: &[(DSPItem::InterstellarLogisticsStation, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::InterstellarLogisticsVessel,
+        facility: Facility::Assembler,
+        time: 6.0,
+        extra_products: false,
+        inputs: &[(DSPItem::TitaniumAlloy, 10), (DSPItem::Processor, 10), (DSPItem::ReinforcedThruster, 2)],
+        outputs: &[(DSPItem::InterstellarLogisticsVessel, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::ElectricMotor,
+        facility: Facility::Assembler,
+        time: 2.0,
+        extra_products: true,
+        inputs: &[(DSPItem::IronIngot, 2), (DSPItem::Gear, 1), (DSPItem::MagneticCoil, 1)],
+        outputs: &[(DSPItem::ElectricMotor, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::ElectromagneticTurbine,
+        facility: Facility::Assembler,
+        time: 2.0,
+        extra_products: true,
+        inputs: &[(DSPItem::ElectricMotor, 2), (DSPItem::MagneticCoil, 2)],
+        outputs: &[(DSPItem::ElectromagneticTurbine, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::ParticleContainer,
+        facility: Facility::Assembler,
+        time: 4.0,
+        extra_products: true,
+        inputs: &[(DSPItem::ElectromagneticTurbine, 2), (DSPItem::CopperIngot, 2), (DSPItem::Graphene, 2)],
+        outputs: &[(DSPItem::ParticleContainer, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::ParticleContainerAdvanced,
+        facility: Facility::Assembler,
+        time: 4.0,
+        extra_products: true,
+        inputs: &[(DSPItem::UnipolarMagnet, 10), (DSPItem::CopperIngot, 2)],
+        outputs: &[(DSPItem::ParticleContainer, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::GravitonLens,
+        facility: Facility::Assembler,
+        time: 6.0,
+        extra_products: true,
+        inputs: &[(DSPItem::Diamond, 4), (DSPItem::StrangeMatter, 1)],
+        outputs: &[(DSPItem::GravitonLens, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::GravityMatrix,
+        facility: Facility::Lab,
+        time: 24.0,
+        extra_products: true,
+        inputs: &[(DSPItem::GravitonLens, 1), (DSPItem::QuantumChip, 1)],
+        outputs: &[(DSPItem::GravityMatrix, 2)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::SupermagneticRing,
+        facility: Facility::Assembler,
+        time: 3.0,
+        extra_products: true,
+        inputs: &[(DSPItem::ElectromagneticTurbine, 2), (DSPItem::Magnet, 3), (DSPItem::EnergeticGraphite, 1)],
+        outputs: &[(DSPItem::SupermagneticRing, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::StrangeMatter,
+        facility: Facility::Collider,
+        time: 8.0,
+        extra_products: true,
+        inputs: &[(DSPItem::ParticleContainer, 2), (DSPItem::IronIngot, 2), (DSPItem::Deuterium, 10)],
+        outputs: &[(DSPItem::StrangeMatter, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::Engine,
+        facility: Facility::Assembler,
+        time: 3.0,
+        extra_products: true,
+        inputs: &[(DSPItem::MagneticCoil, 1), (DSPItem::CopperIngot, 2)],
+        outputs: &[(DSPItem::Engine, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::ProliferatorMkI,
+        facility: Facility::Assembler,
+        time: 0.5,
+        extra_products: true,
+        inputs: &[(DSPItem::Coal, 1)],
+        outputs: &[(DSPItem::ProliferatorMkI, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::ProliferatorMkII,
+        facility: Facility::Assembler,
+        time: 1.0,
+        extra_products: true,
+        inputs: &[(DSPItem::ProliferatorMkI, 2), (DSPItem::Diamond, 1)],
+        outputs: &[(DSPItem::ProliferatorMkII, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::ProliferatorMkIII,
+        facility: Facility::Assembler,
+        time: 2.0,
+        extra_products: true,
+        inputs: &[(DSPItem::ProliferatorMkII, 2), (DSPItem::CarbonNanotube, 1)],
+        outputs: &[(DSPItem::ProliferatorMkIII, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::SprayCoater,
+        facility: Facility::Assembler,
+        time: 3.0,
+        extra_products: false,
+        inputs: &[(DSPItem::Steel, 4), (DSPItem::PlasmaExciter, 2), (DSPItem::CircuitBoard, 2), (DSPItem::MicrocrystallineComponent, 2)],
+        outputs: &[(DSPItem::SprayCoater, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::Fractionator,
+        facility: Facility::Assembler,
+        time: 3.0,
+        extra_products: false,
+        inputs: &[(DSPItem::Steel, 8), (DSPItem::StoneBrick, 4), (DSPItem::Glass, 4), (DSPItem::Processor, 1)],
+        outputs: &[(DSPItem::Fractionator, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::OrbitalCollector,
+        facility: Facility::Assembler,
+        time: 30.0,
+        extra_products: false,
+        inputs: &[(DSPItem::InterstellarLogisticsStation, 1), (DSPItem::SupermagneticRing, 50), (DSPItem::ReinforcedThruster, 20), (DSPItem::AccumulatorFull, 20)],
+        outputs: &[(DSPItem::OrbitalCollector, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::Foundation,
+        facility: Facility::Assembler,
+        time: 1.0,
+        extra_products: true,
+        inputs: &[(DSPItem::StoneBrick, 3), (DSPItem::Steel, 1)],
+        outputs: &[(DSPItem::Foundation, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::MiniFusionPowerPlant,
+        facility: Facility::Assembler,
+        time: 10.0,
+        extra_products: false,
+        inputs: &[(DSPItem::TitaniumAlloy, 12), (DSPItem::SupermagneticRing, 10), (DSPItem::CarbonNanotube, 8), (DSPItem::Processor, 4)],
+        outputs: &[(DSPItem::MiniFusionPowerPlant, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::StorageTank,
+        facility: Facility::Assembler,
+        time: 2.0,
+        extra_products: false,
+        inputs: &[(DSPItem::IronIngot, 8), (DSPItem::StoneBrick, 4), (DSPItem::Glass, 4)],
+        outputs: &[(DSPItem::StorageTank, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::DeuteriumFractionation,
+        facility: Facility::Fractionator,
+        time: 1.0,
+        extra_products: false,
+        inputs: &[(DSPItem::Hydrogen, 100)],
+        outputs: &[(DSPItem::Deuterium, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::PlaneSmelter,
+        facility: Facility::Assembler,
+        time: 5.0,
+        extra_products: false,
+        inputs: &[(DSPItem::ArcSmelter, 1), (DSPItem::FrameMaterial, 5), (DSPItem::PlaneFilter, 4), (DSPItem::UnipolarMagnet, 15)],
+        outputs: &[(DSPItem::PlaneSmelter, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::TrafficMonitor,
+        facility: Facility::Assembler,
+        time: 2.0,
+        extra_products: false,
+        inputs: &[(DSPItem::IronIngot, 3), (DSPItem::Gear, 2), (DSPItem::Glass, 1), (DSPItem::CircuitBoard, 2)],
+        outputs: &[(DSPItem::TrafficMonitor, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::GeothermalPowerStation,
+        facility: Facility::Assembler,
+        time: 6.0,
+        extra_products: false,
+        inputs: &[(DSPItem::Steel, 15), (DSPItem::CopperIngot, 20), (DSPItem::PhotonCombiner, 4), (DSPItem::SupermagneticRing, 1)],
+        outputs: &[(DSPItem::GeothermalPowerStation, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::AdvancedMiningMachine,
+        facility: Facility::Assembler,
+        time: 20.0,
+        extra_products: false,
+        inputs: &[(DSPItem::TitaniumAlloy, 20), (DSPItem::FrameMaterial, 10), (DSPItem::SupermagneticRing, 10), (DSPItem::QuantumChip, 4), (DSPItem::GratingCrystal, 40)],
+        outputs: &[(DSPItem::AdvancedMiningMachine, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::AutomaticPiler,
+        facility: Facility::Assembler,
+        time: 4.0,
+        extra_products: false,
+        inputs: &[(DSPItem::Steel, 3), (DSPItem::Gear, 4), (DSPItem::SupermagneticRing, 1), (DSPItem::Processor, 2)],
+        outputs: &[(DSPItem::AutomaticPiler, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::ReformedRefinement,
+        facility: Facility::Refinery,
+        time: 4.0,
+        extra_products: true,
+        inputs: &[(DSPItem::RefinedOil, 2), (DSPItem::Hydrogen, 1), (DSPItem::Coal, 1)],
+        outputs: &[(DSPItem::RefinedOil, 3)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::LogisticsDistributor,
+        facility: Facility::Assembler,
+        time: 8.0,
+        extra_products: false,
+        inputs: &[(DSPItem::IronIngot, 8), (DSPItem::PlasmaExciter, 4), (DSPItem::Processor, 4)],
+        outputs: &[(DSPItem::LogisticsDistributor, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::LogisticsBot,
+        facility: Facility::Assembler,
+        time: 2.0,
+        extra_products: false,
+        inputs: &[(DSPItem::IronIngot, 2), (DSPItem::Processor, 1), (DSPItem::Thruster, 1)],
+        outputs: &[(DSPItem::LogisticsBot, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::QuantumChemicalPlant,
+        facility: Facility::Assembler,
+        time: 10.0,
+        extra_products: false,
+        inputs: &[(DSPItem::ChemicalPlant, 1), (DSPItem::TitaniumGlass, 10), (DSPItem::StrangeMatter, 3), (DSPItem::QuantumChip, 3)],
+        outputs: &[(DSPItem::QuantumChemicalPlant, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::GaussTurret,
+        facility: Facility::Assembler,
+        time: 3.0,
+        extra_products: false,
+        inputs: &[(DSPItem::Steel, 3), (DSPItem::Gear, 6), (DSPItem::CircuitBoard, 4)],
+        outputs: &[(DSPItem::GaussTurret, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::LaserTurret,
+        facility: Facility::Assembler,
+        time: 3.0,
+        extra_products: false,
+        inputs: &[(DSPItem::Steel, 3), (DSPItem::ElectromagneticTurbine, 3), (DSPItem::PhotonCombiner, 3), (DSPItem::SupermagneticRing, 3)],
+        outputs: &[(DSPItem::LaserTurret, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::ImplosionCannon,
+        facility: Facility::Assembler,
+        time: 4.0,
+        extra_products: false,
+        inputs: &[(DSPItem::Steel, 12), (DSPItem::Gear, 10), (DSPItem::ElectromagneticTurbine, 4)],
+        outputs: &[(DSPItem::ImplosionCannon, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::PlasmaTurret,
+        facility: Facility::Assembler,
+        time: 5.0,
+        extra_products: false,
+        inputs: &[(DSPItem::TitaniumAlloy, 10), (DSPItem::PlasmaExciter, 10), (DSPItem::SupermagneticRing, 5), (DSPItem::Processor, 4)],
+        outputs: &[(DSPItem::PlasmaTurret, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::MissileTurret,
+        facility: Facility::Assembler,
+        time: 6.0,
+        extra_products: false,
+        inputs: &[(DSPItem::TitaniumAlloy, 10), (DSPItem::Processor, 10), (DSPItem::Engine, 6)],
+        outputs: &[(DSPItem::MissileTurret, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::JammerTower,
+        facility: Facility::Assembler,
+        time: 4.0,
+        extra_products: false,
+        inputs: &[(DSPItem::Steel, 6), (DSPItem::MagneticCoil, 12), (DSPItem::CircuitBoard, 6), (DSPItem::ElectricMotor, 3)],
+        outputs: &[(DSPItem::JammerTower, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::SignalTower,
+        facility: Facility::Assembler,
+        time: 6.0,
+        extra_products: false,
+        inputs: &[(DSPItem::Steel, 10), (DSPItem::StoneBrick, 12), (DSPItem::Processor, 8), (DSPItem::PhotonCombiner, 4)],
+        outputs: &[(DSPItem::SignalTower, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::PlanetaryShieldGenerator,
+        facility: Facility::Assembler,
+        time: 10.0,
+        extra_products: false,
+        inputs: &[(DSPItem::Steel, 20), (DSPItem::SupermagneticRing, 20), (DSPItem::ParticleContainer, 8), (DSPItem::Processor, 8)],
+        outputs: &[(DSPItem::PlanetaryShieldGenerator, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::CombustibleUnit,
+        facility: Facility::Assembler,
+        time: 3.0,
+        extra_products: true,
+        inputs: &[(DSPItem::Coal, 3)],
+        outputs: &[(DSPItem::CombustibleUnit, 2)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::ExplosiveUnit,
+        facility: Facility::ChemicalPlant,
+        time: 6.0,
+        extra_products: true,
+        inputs: &[(DSPItem::CombustibleUnit, 2), (DSPItem::Plastic, 2), (DSPItem::SulfuricAcid, 1)],
+        outputs: &[(DSPItem::ExplosiveUnit, 2)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::CrystalExplosiveUnit,
+        facility: Facility::ChemicalPlant,
+        time: 24.0,
+        extra_products: true,
+        inputs: &[(DSPItem::ExplosiveUnit, 8), (DSPItem::CasimirCrystal, 1), (DSPItem::CrystalSilicon, 8)],
+        outputs: &[(DSPItem::CrystalExplosiveUnit, 8)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::MagnumAmmoBox,
+        facility: Facility::Assembler,
+        time: 1.0,
+        extra_products: true,
+        inputs: &[(DSPItem::CopperIngot, 3)],
+        outputs: &[(DSPItem::MagnumAmmoBox, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::TitaniumAmmoBox,
+        facility: Facility::Assembler,
+        time: 2.0,
+        extra_products: true,
+        inputs: &[(DSPItem::MagnumAmmoBox, 1), (DSPItem::TitaniumIngot, 2)],
+        outputs: &[(DSPItem::TitaniumAmmoBox, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::SuperalloyAmmoBox,
+        facility: Facility::Assembler,
+        time: 3.0,
+        extra_products: true,
+        inputs: &[(DSPItem::TitaniumAmmoBox, 1), (DSPItem::TitaniumAlloy, 1)],
+        outputs: &[(DSPItem::SuperalloyAmmoBox, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::ShellSet,
+        facility: Facility::Assembler,
+        time: 1.5,
+        extra_products: true,
+        inputs: &[(DSPItem::Steel, 1), (DSPItem::CombustibleUnit, 2)],
+        outputs: &[(DSPItem::ShellSet, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::HighExplosiveShellSet,
+        facility: Facility::Assembler,
+        time: 3.0,
+        extra_products: true,
+        inputs: &[(DSPItem::ShellSet, 1), (DSPItem::ExplosiveUnit, 2)],
+        outputs: &[(DSPItem::HighExplosiveShellSet, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::CrystalShellSet,
+        facility: Facility::Assembler,
+        time: 6.0,
+        extra_products: true,
+        inputs: &[(DSPItem::HighExplosiveShellSet, 1), (DSPItem::CrystalExplosiveUnit, 1)],
+        outputs: &[(DSPItem::CrystalShellSet, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::PlasmaCapsule,
+        facility: Facility::Assembler,
+        time: 2.0,
+        extra_products: true,
+        inputs: &[(DSPItem::ElectromagneticTurbine, 1), (DSPItem::Deuterium, 12)],
+        outputs: &[(DSPItem::PlasmaCapsule, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::AntimatterCapsule,
+        facility: Facility::Assembler,
+        time: 3.0,
+        extra_products: true,
+        inputs: &[(DSPItem::PlasmaCapsule, 1), (DSPItem::Antimatter, 10), (DSPItem::AnnihilationConstraintSphere, 1)],
+        outputs: &[(DSPItem::AntimatterCapsule, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::MissileSet,
+        facility: Facility::Assembler,
+        time: 2.0,
+        extra_products: true,
+        inputs: &[(DSPItem::CopperIngot, 4), (DSPItem::CircuitBoard, 2), (DSPItem::CombustibleUnit, 2), (DSPItem::Engine, 1)],
+        outputs: &[(DSPItem::MissileSet, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::SupersonicMissileSet,
+        facility: Facility::Assembler,
+        time: 4.0,
+        extra_products: true,
+        inputs: &[(DSPItem::MissileSet, 2), (DSPItem::Processor, 4), (DSPItem::ExplosiveUnit, 4), (DSPItem::Thruster, 2)],
+        outputs: &[(DSPItem::SupersonicMissileSet, 2)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::GravityMissileSet,
+        facility: Facility::Assembler,
+        time: 6.0,
+        extra_products: true,
+        inputs: &[(DSPItem::SupersonicMissileSet, 3), (DSPItem::StrangeMatter, 1), (DSPItem::CrystalExplosiveUnit, 3)],
+        outputs: &[(DSPItem::GravityMissileSet, 3)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::Prototype,
+        facility: Facility::Assembler,
+        time: 2.0,
+        extra_products: false,
+        inputs: &[(DSPItem::IronIngot, 3), (DSPItem::CircuitBoard, 2), (DSPItem::Engine, 1)],
+        outputs: &[(DSPItem::Prototype, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::PrecisionDrone,
+        facility: Facility::Assembler,
+        time: 4.0,
+        extra_products: false,
+        inputs: &[(DSPItem::Prototype, 1), (DSPItem::Processor, 2), (DSPItem::PhotonCombiner, 2)],
+        outputs: &[(DSPItem::PrecisionDrone, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::AttackDrone,
+        facility: Facility::Assembler,
+        time: 4.0,
+        extra_products: false,
+        inputs: &[(DSPItem::Prototype, 1), (DSPItem::Processor, 2), (DSPItem::Engine, 2)],
+        outputs: &[(DSPItem::AttackDrone, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::Corvette,
+        facility: Facility::Assembler,
+        time: 5.0,
+        extra_products: false,
+        inputs: &[(DSPItem::TitaniumAlloy, 40), (DSPItem::Processor, 10), (DSPItem::Engine, 20), (DSPItem::Thruster, 10)],
+        outputs: &[(DSPItem::Corvette, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::Destroyer,
+        facility: Facility::Assembler,
+        time: 8.0,
+        extra_products: false,
+        inputs: &[(DSPItem::FrameMaterial, 20), (DSPItem::QuantumChip, 10), (DSPItem::ReinforcedThruster, 10), (DSPItem::GravitonLens, 2)],
+        outputs: &[(DSPItem::Destroyer, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::BattlefieldAnalysisBase,
+        facility: Facility::Assembler,
+        time: 10.0,
+        extra_products: false,
+        inputs: &[(DSPItem::Steel, 20), (DSPItem::Processor, 10), (DSPItem::LogisticsBot, 8), (DSPItem::LogisticsDistributor, 1)],
+        outputs: &[(DSPItem::BattlefieldAnalysisBase, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::SelfevolutionLab,
+        facility: Facility::Assembler,
+        time: 20.0,
+        extra_products: false,
+        inputs: &[(DSPItem::MatrixLab, 1), (DSPItem::DarkFogMatrix, 20), (DSPItem::QuantumChip, 4), (DSPItem::GravitonLens, 4)],
+        outputs: &[(DSPItem::SelfevolutionLab, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::RecomposingAssembler,
+        facility: Facility::Assembler,
+        time: 16.0,
+        extra_products: false,
+        inputs: &[(DSPItem::AssemblingMachineMkIII, 1), (DSPItem::SiliconbasedNeuron, 18), (DSPItem::MatterRecombinator, 20), (DSPItem::Processor, 8)],
+        outputs: &[(DSPItem::RecomposingAssembler, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::NegentropySmelter,
+        facility: Facility::Assembler,
+        time: 12.0,
+        extra_products: false,
+        inputs: &[(DSPItem::PlaneSmelter, 1), (DSPItem::CoreElement, 10), (DSPItem::EnergyShard, 20), (DSPItem::QuantumChip, 4)],
+        outputs: &[(DSPItem::NegentropySmelter, 1)],
+    },
+    RecipeInfo {
+        recipe: DSPRecipe::StrangeAnnihilationFuelRod,
+        facility: Facility::Assembler,
+        time: 32.0,
+        extra_products: true,
+        inputs: &[(DSPItem::CoreElement, 8), (DSPItem::StrangeMatter, 2), (DSPItem::TitaniumAlloy, 4), (DSPItem::DeuteronFuelRod, 1)],
+        outputs: &[(DSPItem::StrangeAnnihilationFuelRod, 2)],
+    },
+]
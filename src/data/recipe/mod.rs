@@ -0,0 +1,148 @@
+use std::collections::HashMap;
+
+use lazy_static::lazy_static;
+use strum::{AsRefStr, EnumIter, EnumString};
+
+use super::enums::{DSPItem, DSPRecipe};
+
+/// Kinds of buildings making recipes.
+#[derive(EnumString, EnumIter, AsRefStr, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Facility {
+    Smelter,
+    Assembler,
+    ChemicalPlant,
+    Refinery,
+    Collider,
+    Fractionator,
+    Lab,
+}
+
+impl Facility {
+    pub fn of_building(i: DSPItem) -> Option<Self> {
+        match i {
+            DSPItem::ArcSmelter | DSPItem::PlaneSmelter | DSPItem::NegentropySmelter => {
+                Some(Self::Smelter)
+            }
+            DSPItem::AssemblingMachineMkI
+            | DSPItem::AssemblingMachineMkII
+            | DSPItem::AssemblingMachineMkIII
+            | DSPItem::RecomposingAssembler => Some(Self::Assembler),
+            DSPItem::ChemicalPlant | DSPItem::QuantumChemicalPlant => Some(Self::ChemicalPlant),
+            DSPItem::OilRefinery => Some(Self::Refinery),
+            DSPItem::MiniatureParticleCollider => Some(Self::Collider),
+            DSPItem::Fractionator => Some(Self::Fractionator),
+            DSPItem::MatrixLab | DSPItem::SelfevolutionLab => Some(Self::Lab),
+            _ => None,
+        }
+    }
+}
+
+/// Crafting speed of a building, relative to recipe time. Fractionators have none, their
+/// output depends on the belt going through.
+pub fn building_speed(i: DSPItem) -> Option<f64> {
+    match i {
+        DSPItem::AssemblingMachineMkI => Some(0.75),
+        DSPItem::AssemblingMachineMkII => Some(1.0),
+        DSPItem::AssemblingMachineMkIII => Some(1.5),
+        DSPItem::RecomposingAssembler => Some(3.0),
+        DSPItem::ArcSmelter => Some(1.0),
+        DSPItem::PlaneSmelter => Some(2.0),
+        DSPItem::NegentropySmelter => Some(3.0),
+        DSPItem::ChemicalPlant => Some(1.0),
+        DSPItem::QuantumChemicalPlant => Some(2.0),
+        DSPItem::OilRefinery => Some(1.0),
+        DSPItem::MiniatureParticleCollider => Some(1.0),
+        DSPItem::MatrixLab => Some(1.0),
+        DSPItem::SelfevolutionLab => Some(3.0),
+        _ => None,
+    }
+}
+
+pub struct RecipeInfo {
+    pub recipe: DSPRecipe,
+    pub facility: Facility,
+    /// Seconds per craft at speed 1.
+    pub time: f64,
+    /// Whether proliferated ingredients may give extra products, rather than only speed.
+    pub extra_products: bool,
+    pub inputs: &'static [(DSPItem, u32)],
+    pub outputs: &'static [(DSPItem, u32)],
+}
+
+// Generated by update_data.py from data/recipe_details.txt.
+static RECIPES: &[RecipeInfo] = include!("data/recipes.rs");
+
+lazy_static! {
+    static ref RECIPE_INFO: HashMap<DSPRecipe, &'static RecipeInfo> =
+        RECIPES.iter().map(|r| (r.recipe, r)).collect();
+}
+
+// Recipes added to the game after recipe_details.txt was last updated have no info, so all of
+// these are optional.
+impl DSPRecipe {
+    pub fn info(&self) -> Option<&'static RecipeInfo> {
+        RECIPE_INFO.get(self).copied()
+    }
+
+    pub fn inputs(&self) -> Option<&'static [(DSPItem, u32)]> {
+        Some(self.info()?.inputs)
+    }
+
+    pub fn outputs(&self) -> Option<&'static [(DSPItem, u32)]> {
+        Some(self.info()?.outputs)
+    }
+
+    /// Seconds per craft at speed 1.
+    pub fn time(&self) -> Option<f64> {
+        Some(self.info()?.time)
+    }
+
+    /// The kind of building making this recipe.
+    pub fn facility(&self) -> Option<Facility> {
+        Some(self.info()?.facility)
+    }
+
+    pub fn extra_products_allowed(&self) -> Option<bool> {
+        Some(self.info()?.extra_products)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use strum::IntoEnumIterator;
+
+    use crate::data::enums::{DSPItem, DSPRecipe};
+
+    use super::Facility;
+
+    #[test]
+    fn every_recipe_has_info() {
+        for r in DSPRecipe::iter() {
+            assert_eq!(r.info().map(|i| i.recipe), Some(r));
+            assert!(r.time().unwrap() > 0.0);
+            assert!(!r.outputs().unwrap().is_empty());
+        }
+    }
+
+    #[test]
+    fn details() {
+        let r = DSPRecipe::PlasmaRefining;
+        assert_eq!(r.facility(), Some(Facility::Refinery));
+        assert_eq!(r.inputs(), Some(&[(DSPItem::CrudeOil, 2)][..]));
+        assert_eq!(
+            r.outputs(),
+            Some(&[(DSPItem::Hydrogen, 1), (DSPItem::RefinedOil, 2)][..])
+        );
+        assert_eq!(r.time(), Some(4.0));
+        assert_eq!(
+            DSPRecipe::ConveyorBeltMKI.extra_products_allowed(),
+            Some(false)
+        );
+        assert_eq!(DSPRecipe::for_item(&DSPItem::Gear), Some(DSPRecipe::Gear));
+        assert_eq!(
+            DSPRecipe::for_item(&DSPItem::Antimatter),
+            Some(DSPRecipe::MassenergyStorage)
+        );
+        assert_eq!(DSPRecipe::for_item(&DSPItem::IronOre), None);
+    }
+}
@@ -0,0 +1,21 @@
+use binrw::{BinRead, BinWrite};
+#[cfg(feature = "dump")]
+use serde::{Deserialize, Serialize};
+
+use super::visit::{Visit, Visitor};
+
+#[cfg_attr(feature = "dump", derive(Serialize, Deserialize))]
+#[derive(BinRead, BinWrite)]
+#[br(import(param_count: usize))]
+#[br(pre_assert(param_count >= 1))]
+pub struct Sorter {
+    #[br(little)]
+    pub length: u32, // In grid cells, 1 to 3.
+    #[br(count = param_count - 1)]
+    #[br(little)]
+    pub unknown: Vec<u32>,
+}
+
+impl Visit for Sorter {
+    fn visit<T: Visitor + ?Sized>(&mut self, _visitor: &mut T) {}
+}
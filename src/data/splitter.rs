@@ -0,0 +1,28 @@
+use binrw::{BinRead, BinWrite};
+#[cfg(feature = "dump")]
+use serde::{Deserialize, Serialize};
+
+use super::{
+    traits::ItemId,
+    visit::{Visit, Visitor},
+};
+
+#[cfg_attr(feature = "dump", derive(Serialize, Deserialize))]
+#[derive(BinRead, BinWrite)]
+#[br(import(param_count: usize))]
+#[br(pre_assert(param_count >= 3))]
+pub struct Splitter {
+    #[br(little)]
+    pub input_priority: u32, // 0 - none, 1 - prioritized
+    #[br(little)]
+    pub output_priority: u32, // 0 - none, 1 - prioritized
+    #[br(little)]
+    pub filter: ItemId<u32>, // 0 if no filter
+    #[br(count = param_count - 3)]
+    #[br(little)]
+    pub unknown: Vec<u32>,
+}
+
+impl Visit for Splitter {
+    fn visit<T: Visitor + ?Sized>(&mut self, _visitor: &mut T) {}
+}
@@ -1,11 +1,16 @@
 use std::f64::consts::PI;
 
 use binrw::{BinRead, BinWrite};
+use num_enum::{IntoPrimitive, TryFromPrimitive, TryFromPrimitiveError};
 #[cfg(feature = "dump")]
 use serde::{Deserialize, Serialize};
+use strum::{AsRefStr, EnumIter, EnumString};
+
+use crate::error::some_error;
 
 use super::{
-    traits::ItemId,
+    enums::DSPItem,
+    traits::{try_from_user_string_listed, ItemId, TryFromUserString},
     visit::{Visit, Visitor},
 };
 
@@ -15,7 +20,7 @@ pub struct StationHeader {
     #[br(little)]
     pub work_energy_per_tick: u32, // In watts. 1M is 60MW/s.
     #[br(little)]
-    pub drone_range: u32, // cos(angle) * 10^8
+    pub drone_range: u32, // cos(angle) * 10^8, signed
     #[br(little)]
     pub vessel_range: u32, // 1LY = 24000
     #[br(little)]
@@ -37,91 +42,373 @@ impl StationHeader {
     pub const AU: usize = 40000;
     pub fn angle_to_drone_range(angle: usize) -> u32 {
         let angle = f64::cos((angle as f64) / 180.0 * PI);
-        (angle * 100_000_000.0).round() as u32
+        // Past 90 degrees the cosine is negative, the game reads the word as signed.
+        (angle * 100_000_000.0).round() as i32 as u32
+    }
+}
+
+#[derive(
+    TryFromPrimitive,
+    IntoPrimitive,
+    EnumString,
+    EnumIter,
+    AsRefStr,
+    PartialEq,
+    Eq,
+    Clone,
+    Copy,
+    Debug,
+)]
+#[repr(u32)]
+pub enum SlotDirection {
+    #[strum(serialize = "none")]
+    None = 0,
+    #[strum(to_string = "out", serialize = "output")]
+    Output = 1,
+    #[strum(to_string = "in", serialize = "input")]
+    Input = 2,
+}
+
+impl TryFromUserString for SlotDirection {
+    fn try_from_user_string(s: &str) -> anyhow::Result<Self> {
+        try_from_user_string_listed(s, "slot direction")
+    }
+}
+
+// Left and right as seen from the station, facing outwards. In slot order.
+#[derive(EnumString, EnumIter, AsRefStr, PartialEq, Eq, Clone, Copy, Debug)]
+#[strum(serialize_all = "kebab-case")]
+pub enum SlotPosition {
+    NorthRight,
+    NorthCenter,
+    NorthLeft,
+    WestRight,
+    WestCenter,
+    WestLeft,
+    SouthRight,
+    SouthCenter,
+    SouthLeft,
+    EastRight,
+    EastCenter,
+    EastLeft,
+}
+
+impl TryFromUserString for SlotPosition {
+    fn try_from_user_string(s: &str) -> anyhow::Result<Self> {
+        try_from_user_string_listed(s, "slot position")
     }
 }
 
+// Follows StationHeader. Zero in blueprints from before the dark fog update. Drone, vessel and
+// warper counts are never kept, the game only remembers whether to refill them.
+#[cfg_attr(feature = "dump", derive(Serialize, Deserialize))]
+#[derive(BinRead, BinWrite)]
+pub struct StationOptions {
+    #[br(little)]
+    pub drone_auto_replenish: u32,
+    #[br(little)]
+    pub vessel_auto_replenish: u32,
+    #[br(little)]
+    pub remote_group_mask: u64, // Bit n set means the station is in interstellar group n
+    #[br(little)]
+    pub route_priority: u32,
+}
+
 #[cfg_attr(feature = "dump", derive(Serialize, Deserialize))]
 #[derive(BinRead, BinWrite)]
 pub struct StationSlots {
+    // Raw, so that a value from a later game version doesn't fail the whole station.
     #[br(little)]
-    pub direction: u32,
+    pub direction: u32, // SlotDirection
     #[br(little)]
-    pub storage_index: u32,
+    pub storage_index: u32, // 1-based, 0 if none
+    // Slot records are four words, the game only ever writes the first two.
     #[br(little)]
-    pub unused1: u32,
+    #[cfg_attr(feature = "dump", serde(alias = "unused1"))]
+    pub reserved1: u32,
     #[br(little)]
-    pub unused2: u32,
+    #[cfg_attr(feature = "dump", serde(alias = "unused2"))]
+    pub reserved2: u32,
+}
+
+impl StationSlots {
+    pub fn direction(&self) -> Result<SlotDirection, TryFromPrimitiveError<SlotDirection>> {
+        SlotDirection::try_from(self.direction)
+    }
 }
 
 impl Visit for StationSlots {
     fn visit<T: Visitor + ?Sized>(&mut self, _visitor: &mut T) {}
 }
 
+#[derive(
+    TryFromPrimitive,
+    IntoPrimitive,
+    EnumString,
+    EnumIter,
+    AsRefStr,
+    PartialEq,
+    Eq,
+    Clone,
+    Copy,
+    Debug,
+)]
+#[repr(u32)]
+pub enum StationLogic {
+    #[strum(serialize = "storage")]
+    Storage = 0,
+    #[strum(serialize = "supply")]
+    Supply = 1,
+    #[strum(serialize = "demand")]
+    Demand = 2,
+}
+
+impl TryFromUserString for StationLogic {
+    fn try_from_user_string(s: &str) -> anyhow::Result<Self> {
+        try_from_user_string_listed(s, "station logic")
+    }
+}
+
 #[cfg_attr(feature = "dump", derive(Serialize, Deserialize))]
 #[derive(BinRead, BinWrite)]
 pub struct StationStorage {
     #[br(little)]
     pub item_id: ItemId<u32>,
+    // Raw, so that a value from a later game version doesn't fail the whole station.
     #[br(little)]
-    pub local_logic: u32,
+    pub local_logic: u32, // StationLogic
     #[br(little)]
-    pub remote_logic: u32,
+    pub remote_logic: u32, // StationLogic
     #[br(little)]
     pub max_count: u32,
     #[br(little)]
-    pub unused1: u32,
+    #[cfg_attr(feature = "dump", serde(alias = "unused1"))]
+    pub keep_mode: u32, // 0 - none, 1 - keep, stations won't take the ware out
+    // Storage records are six words, the game only ever writes the first five.
     #[br(little)]
-    pub unused2: u32,
+    #[cfg_attr(feature = "dump", serde(alias = "unused2", alias = "unused"))]
+    pub reserved: u32,
+}
+
+impl StationStorage {
+    pub fn local_logic(&self) -> Result<StationLogic, TryFromPrimitiveError<StationLogic>> {
+        StationLogic::try_from(self.local_logic)
+    }
+
+    pub fn remote_logic(&self) -> Result<StationLogic, TryFromPrimitiveError<StationLogic>> {
+        StationLogic::try_from(self.remote_logic)
+    }
 }
 
 impl Visit for StationStorage {
     fn visit<T: Visitor + ?Sized>(&mut self, _visitor: &mut T) {}
 }
 
+/// Parameters of logistics stations and orbital collectors, 2048 words:
+///
+/// * 0: room for 32 storage records of 6 words, of which up to 5 are used.
+/// * 192: room for 32 slot records of 4 words, of which 12 are used.
+/// * 320: [`StationHeader`], then [`StationOptions`] at 329.
+/// * 334 to the end: never written by the game.
+///
+/// Station names, the proliferator points of wares and drone, vessel and warper counts live on
+/// the station in the game and are not kept in blueprints.
 #[cfg_attr(feature = "dump", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dump", serde(try_from = "StationDump"))]
 #[derive(BinRead, BinWrite)]
 #[br(pre_assert(param_count == 2048))]
-#[br(import { is_interstellar: bool, param_count: usize })]
+#[br(import { is_interstellar: bool, is_collector: bool, param_count: usize })]
 pub struct Station {
     #[br(calc = is_interstellar)]
     #[bw(ignore)]
     pub is_interstellar: bool,
+    #[br(calc = is_collector)]
+    #[bw(ignore)]
+    #[cfg_attr(feature = "dump", serde(default))]
+    pub is_collector: bool,
 
     // ignore last 2 if not interstellar
-    #[br(count = 5)]
+    #[br(count = Self::STORAGE)]
     pub storage: Vec<StationStorage>,
-    #[br(count = 192 - 30)]
-    pub unknown1: Vec<u32>,
+    // Raw, so that whatever a later game version puts there survives.
+    #[br(count = Self::MAX_STORAGE - Self::STORAGE)]
+    #[br(little)]
+    pub spare_storage: Vec<[u32; 6]>,
 
-    #[br(count = 12)]
+    #[br(count = Self::SLOTS)]
     pub slots: Vec<StationSlots>, // Counter-clockwise, from rightmost north.
-    #[br(count = 320 - 192 - 48)]
-    pub unknown2: Vec<u32>,
+    #[br(count = Self::MAX_SLOTS - Self::SLOTS)]
+    #[br(little)]
+    pub spare_slots: Vec<[u32; 4]>,
+
+    pub header: StationHeader,   // 320
+    pub options: StationOptions, // 329
+    #[br(count = 2048 - 334)]
+    #[br(little)]
+    pub reserved: Vec<u32>,
+}
+
+/// [`Station`] as found in dumps, which may also use the flat layout of earlier versions:
+/// `unknown1` in place of `spare_storage`, `unknown2` in place of `spare_slots`, and
+/// `unknown3` in place of `options` and `reserved`.
+#[cfg(feature = "dump")]
+#[derive(Deserialize)]
+struct StationDump {
+    #[serde(default)]
+    is_interstellar: bool,
+    #[serde(default)]
+    is_collector: bool,
+    storage: Vec<StationStorage>,
+    spare_storage: Option<Vec<[u32; 6]>>,
+    unknown1: Option<Vec<u32>>,
+    slots: Vec<StationSlots>,
+    spare_slots: Option<Vec<[u32; 4]>>,
+    unknown2: Option<Vec<u32>>,
+    header: StationHeader,
+    options: Option<StationOptions>,
+    reserved: Option<Vec<u32>>,
+    unknown3: Option<Vec<u32>>,
+}
 
-    pub header: StationHeader, // 320
-    #[br(count = 2048 - 320 - 9)]
-    pub unknown3: Vec<u32>,
+#[cfg(feature = "dump")]
+fn records<const N: usize>(
+    new: Option<Vec<[u32; N]>>,
+    old: Option<Vec<u32>>,
+    count: usize,
+    name: &str,
+) -> Result<Vec<[u32; N]>, String> {
+    let records = match (new, old) {
+        (Some(r), _) => r,
+        (None, Some(words)) => words
+            .chunks(N)
+            .map(|c| c.try_into().map_err(|_| format!("{} is cut short", name)))
+            .collect::<Result<_, _>>()?,
+        (None, None) => return Err(format!("missing field `{}`", name)),
+    };
+    if records.len() != count {
+        return Err(format!(
+            "{} has {} records, not {}",
+            name,
+            records.len(),
+            count
+        ));
+    }
+    Ok(records)
+}
+
+#[cfg(feature = "dump")]
+impl TryFrom<StationDump> for Station {
+    type Error = String;
+
+    fn try_from(d: StationDump) -> Result<Self, Self::Error> {
+        let spare_storage = records(
+            d.spare_storage,
+            d.unknown1,
+            Self::MAX_STORAGE - Self::STORAGE,
+            "spare_storage",
+        )?;
+        let spare_slots = records(
+            d.spare_slots,
+            d.unknown2,
+            Self::MAX_SLOTS - Self::SLOTS,
+            "spare_slots",
+        )?;
+        let (options, reserved) = match (d.options, d.reserved, d.unknown3) {
+            (Some(o), Some(r), _) => (o, r),
+            (None, None, Some(u)) if u.len() >= 5 => (
+                StationOptions {
+                    drone_auto_replenish: u[0],
+                    vessel_auto_replenish: u[1],
+                    remote_group_mask: u[2] as u64 | (u[3] as u64) << 32,
+                    route_priority: u[4],
+                },
+                u[5..].to_vec(),
+            ),
+            _ => return Err("missing field `options` or `reserved`".to_string()),
+        };
+        if reserved.len() != 2048 - 334 {
+            return Err(format!(
+                "reserved has {} words, not {}",
+                reserved.len(),
+                2048 - 334
+            ));
+        }
+        Ok(Self {
+            is_interstellar: d.is_interstellar,
+            is_collector: d.is_collector,
+            storage: d.storage,
+            spare_storage,
+            slots: d.slots,
+            spare_slots,
+            header: d.header,
+            options,
+            reserved,
+        })
+    }
 }
 
 impl Station {
+    const STORAGE: usize = 5;
+    const MAX_STORAGE: usize = 32;
+    const SLOTS: usize = 12;
+    const MAX_SLOTS: usize = 32;
+
     fn storage_count(&self) -> usize {
-        if self.is_interstellar {
+        if self.is_collector {
+            2
+        } else if self.is_interstellar {
             5
         } else {
             3
         }
     }
 
-    fn valid_storage(&self) -> &[StationStorage] {
+    pub fn valid_storage(&self) -> &[StationStorage] {
         let count = self.storage_count();
         &self.storage[0..count]
     }
 
-    fn valid_storage_mut(&mut self) -> &mut [StationStorage] {
+    pub fn valid_storage_mut(&mut self) -> &mut [StationStorage] {
         let count = self.storage_count();
         &mut self.storage[0..count]
     }
+
+    pub fn slot(&self, pos: SlotPosition) -> &StationSlots {
+        &self.slots[pos as usize]
+    }
+
+    pub fn slot_ware(&self, pos: SlotPosition) -> Option<ItemId<u32>> {
+        let idx = self.slot(pos).storage_index as usize;
+        let s = self.valid_storage().get(idx.checked_sub(1)?)?;
+        (s.item_id != ItemId(0)).then_some(s.item_id)
+    }
+
+    /// Routes a slot to a ware, which must already be in storage. No ware leaves the slot
+    /// unassigned.
+    pub fn set_slot(
+        &mut self,
+        pos: SlotPosition,
+        direction: SlotDirection,
+        ware: Option<DSPItem>,
+    ) -> anyhow::Result<()> {
+        let storage_index = match ware {
+            None => 0,
+            Some(w) => {
+                let idx = self
+                    .valid_storage()
+                    .iter()
+                    .position(|s| s.item_id == ItemId::from(w))
+                    .ok_or_else(|| {
+                        some_error(format!("Station has no {} in storage.", w.as_ref()))
+                    })?;
+                idx as u32 + 1
+            }
+        };
+        let slot = &mut self.slots[pos as usize];
+        slot.direction = direction.into();
+        slot.storage_index = storage_index;
+        Ok(())
+    }
 }
 
 impl Visit for Station {
@@ -143,8 +430,12 @@ mod test {
 
     use crate::{
         blueprint::Blueprint,
-        data::{building::BuildingParam, enums::DSPItem, station::StationHeader},
-        testutil::get_file,
+        data::{
+            building::BuildingParam,
+            enums::DSPItem,
+            station::{SlotDirection, SlotPosition, Station, StationHeader, StationLogic},
+        },
+        testutil::{example_station, get_file},
     };
 
     #[test]
@@ -179,9 +470,15 @@ mod test {
         assert_eq!(sto[2].item_id.0, DSPItem::StructureMatrix as u32);
         assert_eq!(sto[3].item_id.0, DSPItem::InformationMatrix as u32);
         assert_eq!(sto[4].item_id.0, DSPItem::GravityMatrix as u32);
+        assert!(sto
+            .iter()
+            .all(|s| s.local_logic() == Ok(StationLogic::Supply)));
+        assert!(sto
+            .iter()
+            .all(|s| s.remote_logic() == Ok(StationLogic::Supply)));
 
         let h = &station.header;
-        assert_eq!(h.work_energy_per_tick, 1000_000); // 1 MW per tick, 60 MW per second
+        assert_eq!(h.work_energy_per_tick, 1_000_000); // 1 MW per tick, 60 MW per second
         assert_eq!(h.drone_range, StationHeader::angle_to_drone_range(50));
         assert_eq!(h.vessel_range, (StationHeader::LY * 6) as u32);
         assert_eq!(h.orbital_collector, 1);
@@ -191,13 +488,113 @@ mod test {
         assert_eq!(h.vessel_min_capacity, 40);
         assert_eq!(h.piler_count, 0);
 
+        // Saved before the dark fog update.
+        assert!(sto.iter().all(|s| s.keep_mode == 0 && s.reserved == 0));
+        assert_eq!(station.options.remote_group_mask, 0);
+        assert_eq!(station.options.route_priority, 0);
+        assert_eq!(station.spare_storage.len(), 27);
+        assert_eq!(station.spare_slots.len(), 20);
+        assert_eq!(station.reserved.len(), 2048 - 334);
+        assert!(station.spare_storage.iter().all(|s| *s == [0; 6]));
+        assert!(station.spare_slots.iter().all(|s| *s == [0; 4]));
+        assert!(station.reserved.iter().all(|w| *w == 0));
+        assert!(station
+            .slots
+            .iter()
+            .all(|s| s.reserved1 == 0 && s.reserved2 == 0));
+
         let s = &station.slots;
         let idx: Vec<u32> = s.iter().map(|x| x.storage_index).collect();
         assert_eq!(&idx, &[2, 2, 1, 5, 5, 1, 4, 4, 1, 3, 3, 1]);
+        assert!(s.iter().all(|x| x.direction() == Ok(SlotDirection::Output)));
+        // "From north leftmost clockwise: blue-red-red, blue-yellow-yellow, ..."
+        let ware = |p| DSPItem::try_from(station.slot_ware(p).unwrap()).unwrap();
+        assert_eq!(
+            ware(SlotPosition::NorthLeft),
+            DSPItem::ElectromagneticMatrix
+        );
+        assert_eq!(ware(SlotPosition::NorthRight), DSPItem::EnergyMatrix);
+        assert_eq!(ware(SlotPosition::EastLeft), DSPItem::ElectromagneticMatrix);
+        assert_eq!(ware(SlotPosition::EastCenter), DSPItem::StructureMatrix);
+        assert_eq!(ware(SlotPosition::SouthRight), DSPItem::InformationMatrix);
+        assert_eq!(ware(SlotPosition::WestLeft), DSPItem::ElectromagneticMatrix);
+        assert_eq!(ware(SlotPosition::WestRight), DSPItem::GravityMatrix);
 
         // Can't compare whole blueprints since gzip isn't really reproducible.
         let mut back = vec![];
         bp.data.write_le(&mut Cursor::new(&mut back)).unwrap();
         assert_eq!(raw, back);
     }
+
+    fn station(bp: &mut Blueprint) -> &mut Station {
+        match &mut bp.data.buildings[12].param {
+            BuildingParam::Station(s) => s,
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn example_station_1_fields_round_trip() {
+        let mut bp = example_station();
+        let s = station(&mut bp);
+        s.storage[1].keep_mode = 1;
+        s.storage[4].reserved = 7;
+        s.spare_storage[26] = [1, 2, 3, 4, 5, 6];
+        (s.slots[3].reserved1, s.slots[3].reserved2) = (8, 9);
+        s.spare_slots[0] = [10, 11, 12, 13];
+        s.options.drone_auto_replenish = 1;
+        s.options.vessel_auto_replenish = 1;
+        s.options.remote_group_mask = 1 << 40 | 0b101;
+        s.options.route_priority = 2;
+        s.reserved[0] = 14;
+        s.reserved[2048 - 335] = 15;
+
+        let mut bp = Blueprint::new(&bp.into_bp_string(9).unwrap()).unwrap();
+        let s = station(&mut bp);
+        assert_eq!(s.storage[1].keep_mode, 1);
+        assert_eq!(s.storage[4].reserved, 7);
+        assert_eq!(s.spare_storage[26], [1, 2, 3, 4, 5, 6]);
+        assert_eq!((s.slots[3].reserved1, s.slots[3].reserved2), (8, 9));
+        assert_eq!(s.spare_slots[0], [10, 11, 12, 13]);
+        assert_eq!(s.options.drone_auto_replenish, 1);
+        assert_eq!(s.options.vessel_auto_replenish, 1);
+        assert_eq!(s.options.remote_group_mask, 1 << 40 | 0b101);
+        assert_eq!(s.options.route_priority, 2);
+        assert_eq!((s.reserved[0], s.reserved[2048 - 335]), (14, 15));
+        // Nothing moved around: the header still reads as before.
+        assert_eq!(s.header.work_energy_per_tick, 1_000_000);
+        assert_eq!(s.slots[3].storage_index, 5);
+    }
+
+    // Dumped by a version that kept the station block as flat lists of unknown words.
+    #[cfg(feature = "dump")]
+    #[test]
+    fn old_dump_layout() {
+        let f = get_file("Example interstellar station 1.txt");
+        let (_, raw) = Blueprint::new_with_raw_bp(std::str::from_utf8(&f).unwrap()).unwrap();
+        let f = get_file("Example interstellar station 1, old dump.json");
+        let mut bp = Blueprint::new_from_json(std::str::from_utf8(&f).unwrap()).unwrap();
+        assert_eq!(station(&mut bp).options.route_priority, 0);
+        let mut back = vec![];
+        bp.data.write_le(&mut Cursor::new(&mut back)).unwrap();
+        assert_eq!(raw, back);
+
+        let json = String::from_utf8(bp.dump_json().unwrap()).unwrap();
+        let bad = json.replacen("\"reserved\":[", "\"reserved\":[0,", 1);
+        assert!(Blueprint::new_from_json(&bad).is_err());
+    }
+
+    #[test]
+    fn unknown_logic_and_direction_round_trip() {
+        let mut bp = example_station();
+        station(&mut bp).storage[2].remote_logic = 7;
+        station(&mut bp).slots[5].direction = 9;
+        let mut bp = Blueprint::new(&bp.into_bp_string(9).unwrap()).unwrap();
+        let s = station(&mut bp);
+        assert_eq!(s.storage[2].remote_logic, 7);
+        assert!(s.storage[2].remote_logic().is_err());
+        assert_eq!(s.storage[2].local_logic(), Ok(StationLogic::Supply));
+        assert_eq!(s.slots[5].direction, 9);
+        assert!(s.slots[5].direction().is_err());
+    }
 }
@@ -0,0 +1,68 @@
+use binrw::{BinRead, BinWrite};
+#[cfg(feature = "dump")]
+use serde::{Deserialize, Serialize};
+
+use super::{
+    traits::ItemId,
+    visit::{Visit, Visitor},
+};
+
+#[cfg_attr(feature = "dump", derive(Serialize, Deserialize))]
+#[derive(BinRead, BinWrite)]
+#[br(import(param_count: usize))]
+#[br(pre_assert(param_count >= 1))]
+pub struct Depot {
+    #[br(little)]
+    pub bans: u32, // Number of grid cells closed to sorters, counted from the last one.
+    #[br(count = param_count - 1)]
+    #[br(little)]
+    pub filters: Vec<ItemId<u32>>, // One per grid cell, 0 if not filtered.
+}
+
+impl Visit for Depot {
+    fn visit<T: Visitor + ?Sized>(&mut self, _visitor: &mut T) {}
+}
+
+#[cfg_attr(feature = "dump", derive(Serialize, Deserialize))]
+#[derive(BinRead, BinWrite)]
+#[br(import(param_count: usize))]
+#[br(pre_assert(param_count >= 3))]
+pub struct StorageTank {
+    #[br(little)]
+    pub output_enabled: u32,
+    #[br(little)]
+    pub input_enabled: u32,
+    #[br(little)]
+    pub fluid: ItemId<u32>, // 0 if empty
+    #[br(count = param_count - 3)]
+    #[br(little)]
+    pub unknown: Vec<u32>,
+}
+
+impl Visit for StorageTank {
+    fn visit<T: Visitor + ?Sized>(&mut self, _visitor: &mut T) {}
+}
+
+#[cfg_attr(feature = "dump", derive(Serialize, Deserialize))]
+#[derive(BinRead, BinWrite)]
+#[br(import(param_count: usize))]
+#[br(pre_assert(param_count >= 5))]
+pub struct Distributor {
+    #[br(little)]
+    pub item_id: ItemId<u32>,
+    #[br(little)]
+    pub logic: u32, // Towards other distributors. 0 - none, 1 - supply, 2 - demand
+    #[br(little)]
+    pub icarus_logic: u32, // 0 - none, 1 - supply, 2 - demand, 3 - both
+    #[br(little)]
+    pub work_energy_per_tick: u32, // Same units as StationHeader.
+    #[br(little)]
+    pub auto_replenish_bots: u32,
+    #[br(count = param_count - 5)]
+    #[br(little)]
+    pub unknown: Vec<u32>,
+}
+
+impl Visit for Distributor {
+    fn visit<T: Visitor + ?Sized>(&mut self, _visitor: &mut T) {}
+}
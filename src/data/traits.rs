@@ -1,6 +1,6 @@
 use std::hash::Hash;
 
-use strum::ParseError;
+use strum::{IntoEnumIterator, ParseError};
 
 use binrw::{BinRead, BinWrite};
 #[cfg(feature = "dump")]
@@ -31,6 +31,20 @@ impl<T: DSPEnum> TryFromUserString for T {
     }
 }
 
+// For small strum enums like building modes. Lists valid names on failure.
+pub fn try_from_user_string_listed<T>(s: &str, what: &str) -> anyhow::Result<T>
+where
+    T: for<'a> TryFrom<&'a str> + IntoEnumIterator + AsRef<str>,
+{
+    T::try_from(s).or_else(|_| {
+        let names = T::iter()
+            .map(|e| e.as_ref().to_owned())
+            .collect::<Vec<_>>()
+            .join(", ");
+        anyhow::bail!("Unknown {} '{}'. Supported values: {}.", what, s, names);
+    })
+}
+
 impl DSPEnum for DSPRecipe {
     type Underlying = u16;
     const PRETTY_NAME: &'static str = "recipe";
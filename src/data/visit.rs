@@ -4,7 +4,16 @@ use super::{
     belt::Belt,
     blueprint::BlueprintData,
     building::Building,
+    defense::{BattleBase, DefenseTower, Turret},
+    dyson::Ejector,
+    monitor::TrafficMonitor,
+    piler::Piler,
+    power::{EnergyExchanger, FuelGenerator, RayReceiver},
+    production::{LabParams, ProductionParams},
+    sorter::Sorter,
+    splitter::Splitter,
     station::{Station, StationSlots, StationStorage},
+    storage::{Depot, Distributor, StorageTank},
 };
 
 pub trait Visitor {
@@ -24,6 +33,11 @@ pub trait Visitor {
         v.visit(self)
     }
 
+    // Shares the station layout, but has no drones, vessels or slots worth editing.
+    fn visit_orbital_collector(&mut self, v: &mut Station) {
+        v.visit(self)
+    }
+
     fn visit_station_storage(&mut self, v: &mut StationStorage) {
         v.visit(self)
     }
@@ -32,9 +46,73 @@ pub trait Visitor {
         v.visit(self)
     }
 
+    fn visit_depot(&mut self, v: &mut Depot) {
+        v.visit(self)
+    }
+
+    fn visit_storage_tank(&mut self, v: &mut StorageTank) {
+        v.visit(self)
+    }
+
+    fn visit_distributor(&mut self, v: &mut Distributor) {
+        v.visit(self)
+    }
+
     fn visit_belt(&mut self, v: &mut Belt) {
         v.visit(self)
     }
+
+    fn visit_sorter(&mut self, v: &mut Sorter) {
+        v.visit(self)
+    }
+
+    fn visit_splitter(&mut self, v: &mut Splitter) {
+        v.visit(self)
+    }
+
+    fn visit_piler(&mut self, v: &mut Piler) {
+        v.visit(self)
+    }
+
+    fn visit_traffic_monitor(&mut self, v: &mut TrafficMonitor) {
+        v.visit(self)
+    }
+
+    fn visit_ejector(&mut self, v: &mut Ejector) {
+        v.visit(self)
+    }
+
+    fn visit_turret(&mut self, v: &mut Turret) {
+        v.visit(self)
+    }
+
+    fn visit_defense_tower(&mut self, v: &mut DefenseTower) {
+        v.visit(self)
+    }
+
+    fn visit_battle_base(&mut self, v: &mut BattleBase) {
+        v.visit(self)
+    }
+
+    fn visit_fuel_generator(&mut self, v: &mut FuelGenerator) {
+        v.visit(self)
+    }
+
+    fn visit_ray_receiver(&mut self, v: &mut RayReceiver) {
+        v.visit(self)
+    }
+
+    fn visit_energy_exchanger(&mut self, v: &mut EnergyExchanger) {
+        v.visit(self)
+    }
+
+    fn visit_production(&mut self, v: &mut ProductionParams) {
+        v.visit(self)
+    }
+
+    fn visit_lab(&mut self, v: &mut LabParams) {
+        v.visit(self)
+    }
 }
 
 pub trait Visit {
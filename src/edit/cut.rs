@@ -0,0 +1,99 @@
+use crate::{
+    blueprint::Blueprint,
+    data::{
+        area::Area,
+        blueprint::{BlueprintData, Header},
+        building::Building,
+        visit::Visitor,
+    },
+    graph::renumber,
+};
+
+use super::transform::Translate;
+
+// Building offsets are cell indices; a building belongs to the cell its offset rounds to.
+fn cell(b: &Building) -> (i64, i64) {
+    (
+        b.header.local_offset_x.round() as i64,
+        b.header.local_offset_y.round() as i64,
+    )
+}
+
+/// Keeps only buildings within cells `x0..=x1`, `y0..=y1` and makes that box the new drag box.
+pub fn crop(data: &mut BlueprintData, x0: u32, y0: u32, x1: u32, y1: u32) {
+    let xs = x0 as i64..=x1 as i64;
+    let ys = y0 as i64..=y1 as i64;
+    data.buildings.retain(|b| {
+        let (x, y) = cell(b);
+        xs.contains(&x) && ys.contains(&y)
+    });
+    renumber(data);
+    Translate {
+        dx: -(x0 as f32),
+        dy: -(y0 as f32),
+        dz: 0.0,
+    }
+    .visit_blueprint_data(data);
+
+    let (w, h) = (x1 - x0 + 1, y1 - y0 + 1);
+    let header = &mut data.header;
+    header.dragbox_size_x = w;
+    header.dragbox_size_y = h;
+    header.cursor_offset_x = header.cursor_offset_x.clamp(x0, x1) - x0;
+    header.cursor_offset_y = header.cursor_offset_y.clamp(y0, y1) - y0;
+    for a in data.areas.iter_mut() {
+        a.width = w as u16;
+        a.height = h as u16;
+    }
+}
+
+/// Makes a blueprint of each area. Icons and description are copied to all of them.
+pub fn split_by_area(bp: Blueprint) -> Vec<Blueprint> {
+    let old = bp.data.header;
+    let mut buildings = bp.data.buildings;
+    let mut out = vec![];
+    for a in bp.data.areas {
+        let mine: Vec<Building>;
+        (mine, buildings) = buildings
+            .into_iter()
+            .partition(|b| b.header.area_index == a.index);
+        let (cursor_x, cursor_y) = if old.cursor_target_area == a.index as u32 {
+            (old.cursor_offset_x, old.cursor_offset_y)
+        } else {
+            (a.width as u32 / 2, a.height as u32 / 2)
+        };
+        let mut data = BlueprintData {
+            header: Header {
+                version: old.version,
+                cursor_offset_x: cursor_x,
+                cursor_offset_y: cursor_y,
+                cursor_target_area: 0,
+                dragbox_size_x: a.width as u32,
+                dragbox_size_y: a.height as u32,
+                primary_area_index: 0,
+                area_count: 1,
+            },
+            areas: vec![Area {
+                index: 0,
+                parent_index: -1,
+                ..a
+            }],
+            building_count: 0,
+            buildings: mine,
+        };
+        for b in data.buildings.iter_mut() {
+            b.header.area_index = 0;
+        }
+        renumber(&mut data);
+        out.push(Blueprint {
+            layout: bp.layout,
+            icons: bp.icons,
+            timestamp: bp.timestamp,
+            game_version: bp.game_version.clone(),
+            icon_text: bp.icon_text.clone(),
+            desc: bp.desc.clone(),
+            data,
+        });
+    }
+    out
+}
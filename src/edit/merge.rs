@@ -0,0 +1,90 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+
+use crate::{
+    data::{blueprint::BlueprintData, visit::Visitor},
+    error::some_error,
+    graph::{renumber, rewrite_indices},
+};
+
+use super::transform::Translate;
+
+fn shift(data: &mut BlueprintData, dx: u32, dy: u32) {
+    if dx == 0 && dy == 0 {
+        return;
+    }
+    Translate {
+        dx: dx as f32,
+        dy: dy as f32,
+        dz: 0.0,
+    }
+    .visit_blueprint_data(data);
+    let h = &mut data.header;
+    h.cursor_offset_x += dx;
+    h.cursor_offset_y += dy;
+    h.dragbox_size_x += dx;
+    h.dragbox_size_y += dy;
+    let primary = h.primary_area_index as i8;
+    for a in data.areas.iter_mut().filter(|a| a.index != primary) {
+        a.anchor_local_offset_x += dx as u16;
+        a.anchor_local_offset_y += dy as u16;
+    }
+}
+
+/// Puts the buildings of `other` into `data`, with the drag box corner of `other` at
+/// `(x, y)` cells from the drag box corner of `data`. The primary areas become one, other
+/// areas of `other` are appended.
+pub fn merge(data: &mut BlueprintData, mut other: BlueprintData, x: i32, y: i32) -> Result<()> {
+    let primary = data.header.primary_area_index as i8;
+    let other_primary = other.header.primary_area_index as i8;
+    // Area indices need not be contiguous, new ones go after the highest index in use.
+    let mut area_map = HashMap::from([(other_primary, primary), (-1, -1)]);
+    let mut next = data.areas.iter().map(|a| a.index).max().unwrap_or(primary);
+    for a in other.areas.iter().filter(|a| a.index != other_primary) {
+        next = next.checked_add(1).ok_or_else(|| {
+            some_error(format!("Merging would need area indices past {}.", i8::MAX))
+        })?;
+        area_map.insert(a.index, next);
+    }
+    let area = |i: i8| area_map.get(&i).copied().unwrap_or(primary);
+
+    // Both blueprints get moved so that nothing ends up at negative offsets.
+    let (sx, sy) = ((-x).max(0) as u32, (-y).max(0) as u32);
+    let (ox, oy) = (x.max(0) as u32, y.max(0) as u32);
+    shift(data, sx, sy);
+    shift(&mut other, ox, oy);
+
+    let base = data.buildings.len() as u32;
+    let map: HashMap<u32, u32> = other
+        .buildings
+        .iter()
+        .enumerate()
+        .map(|(i, b)| (b.header.index, base + i as u32))
+        .collect();
+    rewrite_indices(&mut other.buildings, &map);
+    renumber(data);
+
+    for mut a in other.areas {
+        if a.index != other_primary {
+            a.index = area(a.index);
+            a.parent_index = area(a.parent_index);
+            data.areas.push(a);
+        }
+    }
+    for mut b in other.buildings {
+        b.header.area_index = area(b.header.area_index);
+        data.buildings.push(b);
+    }
+
+    let h = &mut data.header;
+    h.dragbox_size_x = h.dragbox_size_x.max(other.header.dragbox_size_x);
+    h.dragbox_size_y = h.dragbox_size_y.max(other.header.dragbox_size_y);
+    if let Some(a) = data.areas.iter_mut().find(|a| a.index == primary) {
+        a.width = a.width.max(h.dragbox_size_x as u16);
+        a.height = a.height.max(h.dragbox_size_y as u16);
+    }
+    h.area_count = data.areas.len() as u8;
+    data.building_count = data.buildings.len() as u32;
+    Ok(())
+}
@@ -5,19 +5,37 @@ use anyhow::Result;
 use crate::{
     blueprint::Blueprint,
     data::{
-        enums::{BuildingClass, DSPItem, DSPRecipe},
-        traits::DSPEnum,
+        building::Building,
+        enums::{BPModel, BuildingClass, DSPItem, DSPRecipe},
+        power::{ExchangerMode, RayReceiverMode},
+        production::ProliferatorMode,
+        station::{SlotDirection, SlotPosition},
+        traits::{BPModelId, DSPEnum},
         visit::Visitor,
     },
+    error::some_error,
+    graph::renumber,
 };
 
 use self::{
     replace::{Replace, ReplaceBuilding, ReplaceItem, ReplaceRecipe},
+    settings::{
+        SetEjectorOrbit, SetExchangerMode, SetMonitorFlow, SetProliferatorMode, SetRayReceiverMode,
+    },
+    station::{SetStationSettings, SetStationSlot, SetWareSettings, StationSettings, WareSettings},
     stats::GetStats,
+    transform::{Axis, Mirror, RotateQuarter, Translate},
 };
 
-pub(crate) mod replace;
-pub(crate) mod stats;
+pub mod cut;
+pub mod merge;
+pub mod power;
+pub mod rates;
+pub mod replace;
+pub mod settings;
+pub mod station;
+pub mod stats;
+pub mod transform;
 
 fn map_using_map<T: DSPEnum + 'static>(m: HashMap<T, T>) -> Box<Replace<T>> {
     Box::new(move |from| *m.get(&from).unwrap_or(&from))
@@ -35,7 +53,7 @@ impl EditBlueprint {
     }
 
     pub fn set_icon_text(&mut self, test: &str) {
-        self.0.set_icon_text(&test);
+        self.0.set_icon_text(test);
     }
 
     pub fn get_description(&self) -> Result<String> {
@@ -73,19 +91,607 @@ impl EditBlueprint {
         self.replace_recipe(r2);
     }
 
+    fn known_models(&self) -> HashMap<DSPItem, BPModelId<u16>> {
+        self.0
+            .data
+            .buildings
+            .iter()
+            .filter_map(|b| Some((b.kind().ok()?, b.header.model_index)))
+            .collect()
+    }
+
     pub fn replace_building(&mut self, map: HashMap<DSPItem, DSPItem>) -> Result<()> {
+        let models = self.known_models();
         map.iter().try_for_each(|(i, o)| {
             if !BuildingClass::replacement_is_valid(*i, *o) {
                 let e: crate::error::Error =
                     format!("Cannot replace buildings: {} -> {}", i.as_ref(), o.as_ref()).into();
                 Err(anyhow::Error::from(e))
+            } else if i != o && BPModel::from_building(*o).is_err() && !models.contains_key(o) {
+                let e: crate::error::Error = format!(
+                    "Cannot replace buildings: {} -> {}. Model of {} is unknown, place one in the blueprint first.",
+                    i.as_ref(),
+                    o.as_ref(),
+                    o.as_ref()
+                )
+                .into();
+                Err(anyhow::Error::from(e))
             } else {
                 Ok(())
             }
         })?;
         let m = map_using_map(map);
-        let mut r = ReplaceBuilding::new(&m);
+        let mut r = ReplaceBuilding::new(&m, models);
         r.visit_blueprint(&mut self.0);
         Ok(())
     }
+
+    pub fn set_proliferator_mode(&mut self, mode: ProliferatorMode) {
+        SetProliferatorMode(mode).visit_blueprint(&mut self.0);
+    }
+
+    pub fn set_monitor_flow(&mut self, items_per_minute: f64) {
+        SetMonitorFlow(items_per_minute).visit_blueprint(&mut self.0);
+    }
+
+    pub fn set_ray_receiver_mode(&mut self, mode: RayReceiverMode) {
+        SetRayReceiverMode(mode).visit_blueprint(&mut self.0);
+    }
+
+    pub fn set_exchanger_mode(&mut self, mode: ExchangerMode) {
+        SetExchangerMode(mode).visit_blueprint(&mut self.0);
+    }
+
+    pub fn set_ejector_orbit(&mut self, orbit: u32) {
+        SetEjectorOrbit(orbit).visit_blueprint(&mut self.0);
+    }
+
+    pub fn set_station_settings(&mut self, settings: &StationSettings, ware: Option<DSPItem>) {
+        SetStationSettings::new(settings, ware).visit_blueprint(&mut self.0);
+    }
+
+    pub fn set_ware_settings(
+        &mut self,
+        ware: DSPItem,
+        settings: &WareSettings,
+        station_ware: Option<DSPItem>,
+    ) {
+        SetWareSettings::new(ware, settings, station_ware).visit_blueprint(&mut self.0);
+    }
+
+    pub fn set_station_slot(
+        &mut self,
+        pos: SlotPosition,
+        direction: SlotDirection,
+        ware: Option<DSPItem>,
+        station_ware: Option<DSPItem>,
+    ) -> Result<()> {
+        let mut s = SetStationSlot::new(pos, direction, ware, station_ware);
+        s.visit_blueprint(&mut self.0);
+        s.result
+    }
+
+    /// Moves all buildings by whole cells sideways and any height. The cursor moves along, and
+    /// the drag box and areas grow to fit. Nothing may end up at negative offsets.
+    pub fn translate(&mut self, dx: f32, dy: f32, dz: f32) -> Result<()> {
+        if dx.fract() != 0.0 || dy.fract() != 0.0 {
+            return Err(some_error(format!(
+                "Move offset {},{} must be whole cells.",
+                dx, dy
+            )));
+        }
+        let data = &mut self.0.data;
+        let (cx, cy) = (
+            data.header.cursor_offset_x as i64 + dx as i64,
+            data.header.cursor_offset_y as i64 + dy as i64,
+        );
+        // Offsets are a little off whole cells, compare the cells buildings are in.
+        let negative = data.buildings.iter().any(|b| {
+            let h = &b.header;
+            [
+                h.local_offset_x + dx,
+                h.local_offset_y + dy,
+                h.local_offset_z + dz,
+                h.local_offset_x2 + dx,
+                h.local_offset_y2 + dy,
+                h.local_offset_z2 + dz,
+            ]
+            .iter()
+            .any(|c| c.round() < 0.0)
+        });
+        if negative || cx < 0 || cy < 0 {
+            return Err(some_error(format!(
+                "Moving by {},{},{} would put buildings or the cursor at negative offsets.",
+                dx, dy, dz
+            )));
+        }
+
+        Translate { dx, dy, dz }.visit_blueprint_data(data);
+        let h = &mut data.header;
+        h.cursor_offset_x = cx as u32;
+        h.cursor_offset_y = cy as u32;
+        for b in data.buildings.iter() {
+            let (x, y) = (b.header.local_offset_x, b.header.local_offset_y);
+            h.dragbox_size_x = h.dragbox_size_x.max(x.round() as u32 + 1);
+            h.dragbox_size_y = h.dragbox_size_y.max(y.round() as u32 + 1);
+        }
+        let primary = h.primary_area_index as i8;
+        if let Some(a) = data.areas.iter_mut().find(|a| a.index == primary) {
+            a.width = a.width.max(h.dragbox_size_x as u16);
+            a.height = a.height.max(h.dragbox_size_y as u16);
+        }
+        Ok(())
+    }
+
+    /// Sets the point the blueprint is held by when pasting. Must be within the drag box.
+    pub fn set_cursor_offset(&mut self, x: u32, y: u32) -> Result<()> {
+        let h = &mut self.0.data.header;
+        if x >= h.dragbox_size_x || y >= h.dragbox_size_y {
+            return Err(some_error(format!(
+                "Cursor offset {},{} is outside of the {}x{} drag box.",
+                x, y, h.dragbox_size_x, h.dragbox_size_y
+            )));
+        }
+        h.cursor_offset_x = x;
+        h.cursor_offset_y = y;
+        Ok(())
+    }
+
+    pub fn center_cursor(&mut self) {
+        let h = &mut self.0.data.header;
+        h.cursor_offset_x = h.dragbox_size_x / 2;
+        h.cursor_offset_y = h.dragbox_size_y / 2;
+    }
+
+    /// Adds the buildings of another blueprint, with its drag box corner at `(x, y)` cells
+    /// from ours. Icons and description stay ours. Fails if the areas don't fit in the index
+    /// range.
+    pub fn merge(&mut self, other: Blueprint, x: i32, y: i32) -> Result<()> {
+        merge::merge(&mut self.0.data, other.data, x, y)
+    }
+
+    /// Keeps only buildings within the cells `x0..=x1`, `y0..=y1` of the drag box, which
+    /// becomes the new drag box. Connections to removed buildings are cleared.
+    pub fn crop(&mut self, x0: u32, y0: u32, x1: u32, y1: u32) -> Result<()> {
+        let h = &self.0.data.header;
+        if x0 > x1 || y0 > y1 || x1 >= h.dragbox_size_x || y1 >= h.dragbox_size_y {
+            return Err(some_error(format!(
+                "Box {},{},{},{} is empty or not within the {}x{} drag box.",
+                x0, y0, x1, y1, h.dragbox_size_x, h.dragbox_size_y
+            )));
+        }
+        if self.0.data.header.area_count != 1 {
+            return Err(some_error(
+                "Only blueprints with a single area can be cropped. Split them by area first.",
+            ));
+        }
+        cut::crop(&mut self.0.data, x0, y0, x1, y1);
+        Ok(())
+    }
+
+    /// Keeps only buildings for which `f` is true. Connections to removed buildings are
+    /// cleared.
+    pub fn retain<F: FnMut(&Building) -> bool>(&mut self, f: F) {
+        self.0.data.buildings.retain(f);
+        renumber(&mut self.0.data);
+    }
+
+    pub fn split_by_area(self) -> Vec<Blueprint> {
+        cut::split_by_area(self.0)
+    }
+
+    // Rotating and mirroring flip cells within the drag box, so the cursor and all areas must
+    // lie inside it.
+    fn check_in_drag_box(&self) -> Result<()> {
+        let h = &self.0.data.header;
+        let (w, hh) = (h.dragbox_size_x, h.dragbox_size_y);
+        if h.cursor_offset_x >= w || h.cursor_offset_y >= hh {
+            return Err(some_error(format!(
+                "Cursor {},{} is outside the {}x{} drag box.",
+                h.cursor_offset_x, h.cursor_offset_y, w, hh
+            )));
+        }
+        for a in self.0.data.areas.iter() {
+            let x1 = a.anchor_local_offset_x as u32 + a.width as u32;
+            let y1 = a.anchor_local_offset_y as u32 + a.height as u32;
+            if x1 > w || y1 > hh {
+                return Err(some_error(format!(
+                    "Area {} does not fit in the {}x{} drag box.",
+                    a.index, w, hh
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Rotates the blueprint clockwise within its drag box. Degrees must be a multiple of 90.
+    pub fn rotate(&mut self, degrees: i32) -> Result<()> {
+        if degrees % 90 != 0 {
+            return Err(some_error(format!(
+                "Can only rotate by multiples of 90 degrees, not {}.",
+                degrees
+            )));
+        }
+        self.check_in_drag_box()?;
+        for _ in 0..(degrees / 90).rem_euclid(4) {
+            RotateQuarter::new().visit_blueprint(&mut self.0);
+        }
+        Ok(())
+    }
+
+    /// Mirrors the blueprint within its drag box. Station slots and splitter sides are swapped
+    /// along, so belts stay connected.
+    pub fn mirror(&mut self, axis: Axis) -> Result<()> {
+        self.check_in_drag_box()?;
+        Mirror::new(axis).visit_blueprint(&mut self.0);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use crate::{
+        data::{
+            area::Area,
+            building::BuildingParam,
+            enums::DSPItem,
+            station::{SlotDirection, SlotPosition, Station, StationLogic},
+            traits::BPModelId,
+        },
+        testutil::{building, example_station},
+    };
+
+    use super::{
+        station::{StationSettings, WareSettings},
+        transform::Axis,
+        EditBlueprint,
+    };
+
+    fn station(bp: &mut EditBlueprint) -> &mut Station {
+        bp.0.data
+            .buildings
+            .iter_mut()
+            .find_map(|b| match &mut b.param {
+                BuildingParam::Station(s) => Some(s),
+                _ => None,
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn replace_turret_needs_known_model() {
+        let mut bp = EditBlueprint::new(example_station());
+        bp.0.data
+            .buildings
+            .push(building(DSPItem::GaussTurret, &[]));
+        let replace = HashMap::from([(DSPItem::GaussTurret, DSPItem::MissileTurret)]);
+        assert!(bp.replace_building(replace.clone()).is_err());
+
+        let mut missile = building(DSPItem::MissileTurret, &[]);
+        missile.header.model_index = BPModelId(1234);
+        bp.0.data.buildings.push(missile);
+        bp.replace_building(replace).unwrap();
+        let turrets: Vec<_> =
+            bp.0.data
+                .buildings
+                .iter()
+                .filter(|b| b.kind() == Ok(DSPItem::MissileTurret))
+                .map(|b| b.header.model_index)
+                .collect();
+        assert_eq!(turrets, [BPModelId(1234), BPModelId(1234)]);
+
+        let belts = HashMap::from([(DSPItem::GaussTurret, DSPItem::ConveyorBeltMKI)]);
+        assert!(bp.replace_building(belts).is_err());
+    }
+
+    #[test]
+    fn station_settings() {
+        let mut bp = EditBlueprint::new(example_station());
+        let settings = StationSettings {
+            work_energy_per_tick: Some(500_000),
+            equip_warper: Some(false),
+            ..Default::default()
+        };
+        bp.set_station_settings(&settings, Some(DSPItem::IronOre));
+        assert_eq!(station(&mut bp).header.work_energy_per_tick, 1_000_000);
+        bp.set_station_settings(&settings, Some(DSPItem::GravityMatrix));
+        let h = &station(&mut bp).header;
+        assert_eq!(h.work_energy_per_tick, 500_000);
+        assert_eq!(h.equip_warper, 0);
+        assert_eq!(h.drone_min_capacity, 20);
+    }
+
+    #[test]
+    fn ware_settings() {
+        let mut bp = EditBlueprint::new(example_station());
+        let settings = WareSettings {
+            remote_logic: Some(StationLogic::Demand),
+            max_count: Some(5000),
+            ..Default::default()
+        };
+        bp.set_ware_settings(DSPItem::GravityMatrix, &settings, None);
+        let s = station(&mut bp);
+        assert_eq!(s.storage[4].remote_logic(), Ok(StationLogic::Demand));
+        assert_eq!(s.storage[4].local_logic(), Ok(StationLogic::Supply));
+        assert_eq!(s.storage[4].max_count, 5000);
+        assert_eq!(s.storage[3].remote_logic(), Ok(StationLogic::Supply));
+    }
+
+    #[test]
+    fn station_slot() {
+        let mut bp = EditBlueprint::new(example_station());
+        bp.set_station_slot(
+            SlotPosition::SouthCenter,
+            SlotDirection::Input,
+            Some(DSPItem::GravityMatrix),
+            None,
+        )
+        .unwrap();
+        let s = station(&mut bp);
+        assert_eq!(
+            s.slot(SlotPosition::SouthCenter).direction(),
+            Ok(SlotDirection::Input)
+        );
+        assert_eq!(
+            s.slot_ware(SlotPosition::SouthCenter),
+            Some(DSPItem::GravityMatrix.into())
+        );
+        let r = bp.set_station_slot(
+            SlotPosition::SouthCenter,
+            SlotDirection::Input,
+            Some(DSPItem::IronOre),
+            None,
+        );
+        assert!(r.is_err());
+    }
+
+    #[test]
+    fn translate() {
+        let mut bp = EditBlueprint::new(example_station());
+        let before: Vec<_> =
+            bp.0.data
+                .buildings
+                .iter()
+                .map(|b| (b.header.local_offset_x, b.header.local_offset_z2))
+                .collect();
+        let h = &bp.0.data.header;
+        let cursor = (h.cursor_offset_x, h.cursor_offset_y);
+        assert_eq!((h.dragbox_size_x, h.dragbox_size_y), (9, 9));
+        bp.translate(2.0, 1.0, 1.5).unwrap();
+        for (b, (x, z2)) in bp.0.data.buildings.iter().zip(before) {
+            assert_eq!(b.header.local_offset_x, x + 2.0);
+            assert_eq!(b.header.local_offset_z2, z2 + 1.5);
+        }
+        let h = &bp.0.data.header;
+        assert_eq!(
+            (h.cursor_offset_x, h.cursor_offset_y),
+            (cursor.0 + 2, cursor.1 + 1)
+        );
+        assert_eq!((h.dragbox_size_x, h.dragbox_size_y), (11, 10));
+        let a = &bp.0.data.areas[0];
+        assert_eq!((a.width, a.height), (11, 10));
+
+        // Back is fine, any further would leave the grid.
+        bp.translate(-2.0, -1.0, 0.0).unwrap();
+        assert_eq!(bp.0.data.header.cursor_offset_x, cursor.0);
+        assert!(bp.translate(-1.0, 0.0, 0.0).is_err());
+        assert!(bp.translate(0.0, 0.0, -2.0).is_err());
+        assert!(bp.translate(0.5, 0.0, 0.0).is_err());
+        assert_eq!(bp.0.data.header.cursor_offset_x, cursor.0);
+
+        let h = &bp.0.data.header;
+        let (w, hh) = (h.dragbox_size_x, h.dragbox_size_y);
+        assert!(bp.set_cursor_offset(w, 0).is_err());
+        bp.set_cursor_offset(0, hh - 1).unwrap();
+        assert_eq!(bp.0.data.header.cursor_offset_y, hh - 1);
+    }
+
+    #[test]
+    fn rotate() {
+        let mut bp = EditBlueprint::new(example_station());
+        let pos = |bp: &EditBlueprint| -> Vec<_> {
+            bp.0.data
+                .buildings
+                .iter()
+                .map(|b| {
+                    (
+                        b.header.local_offset_x,
+                        b.header.local_offset_y,
+                        b.header.yaw,
+                    )
+                })
+                .collect()
+        };
+        let orig = pos(&bp);
+        let cursor = bp.0.data.header.cursor_offset_x;
+        bp.rotate(90).unwrap();
+        // The belt south of the station ends up west of it.
+        let b = &bp.0.data.buildings[4];
+        assert!((b.header.yaw - 270.0).abs() < 0.01);
+        assert!(b.header.local_offset_x < 2.0);
+        assert!((b.header.local_offset_y - 4.0).abs() < 0.1);
+        assert!(bp.rotate(45).is_err());
+        bp.rotate(270).unwrap();
+        for (a, b) in pos(&bp).into_iter().zip(orig) {
+            assert!((a.0 - b.0).abs() < 1e-4);
+            assert!((a.1 - b.1).abs() < 1e-4);
+            assert!(
+                (a.2 - b.2)
+                    .rem_euclid(360.0)
+                    .min((b.2 - a.2).rem_euclid(360.0))
+                    < 1e-3
+            );
+        }
+        assert_eq!(bp.0.data.header.cursor_offset_x, cursor);
+    }
+
+    fn with_extra_area(bp: &mut EditBlueprint) {
+        let d = &mut bp.0.data;
+        let a = &d.areas[0];
+        d.areas.push(Area {
+            index: 1,
+            parent_index: 0,
+            tropic_anchor: a.tropic_anchor,
+            area_segments: a.area_segments,
+            anchor_local_offset_x: 1,
+            anchor_local_offset_y: 2,
+            width: 3,
+            height: 4,
+        });
+        d.header.area_count = 2;
+    }
+
+    #[test]
+    fn rotate_cursor_and_areas() {
+        let mut bp = EditBlueprint::new(example_station());
+        with_extra_area(&mut bp);
+        bp.0.data.header.cursor_offset_x = 1;
+        bp.0.data.header.cursor_offset_y = 2;
+        bp.rotate(90).unwrap();
+        let d = &bp.0.data;
+        assert_eq!((d.header.cursor_offset_x, d.header.cursor_offset_y), (2, 7));
+        let a = &d.areas[1];
+        assert_eq!((a.anchor_local_offset_x, a.anchor_local_offset_y), (2, 5));
+        assert_eq!((a.width, a.height), (4, 3));
+        assert_eq!(a.parent_index, 0);
+        bp.rotate(270).unwrap();
+        let a = &bp.0.data.areas[1];
+        assert_eq!((a.anchor_local_offset_x, a.anchor_local_offset_y), (1, 2));
+        assert_eq!((a.width, a.height), (3, 4));
+
+        bp.0.data.header.cursor_offset_x = 9;
+        assert!(bp.rotate(90).is_err());
+        bp.0.data.header.cursor_offset_x = 0;
+        bp.0.data.areas[1].width = 9;
+        assert!(bp.mirror(Axis::X).is_err());
+    }
+
+    #[test]
+    fn mirror() {
+        let mut bp = EditBlueprint::new(example_station());
+        let slot = |bp: &mut EditBlueprint, i: usize| {
+            let s = &station(bp).slots[i];
+            (s.direction, s.storage_index)
+        };
+        let west = slot(&mut bp, 4);
+        let east = slot(&mut bp, 10);
+        // Belt 5 is fed from the west center slot.
+        assert_eq!(bp.0.data.buildings[5].header.input_from_slot, 4);
+        bp.mirror(Axis::X).unwrap();
+        let b = &bp.0.data.buildings[5].header;
+        assert_eq!(b.input_from_slot, 10);
+        assert!(b.local_offset_x > 6.0);
+        assert!((b.yaw - 90.0).abs() < 1.5);
+        assert_eq!(slot(&mut bp, 10), west);
+        assert_eq!(slot(&mut bp, 4), east);
+
+        with_extra_area(&mut bp);
+        bp.0.data.header.cursor_offset_y = 2;
+        bp.mirror(Axis::Y).unwrap();
+        let d = &bp.0.data;
+        assert_eq!(d.header.cursor_offset_y, 6);
+        let a = &d.areas[1];
+        assert_eq!((a.anchor_local_offset_x, a.anchor_local_offset_y), (1, 3));
+        assert_eq!((a.width, a.height), (3, 4));
+    }
+
+    #[test]
+    fn merge() {
+        let mut bp = EditBlueprint::new(example_station());
+        let n = bp.0.data.buildings.len() as u32;
+        bp.merge(example_station(), 10, -2).unwrap();
+        let d = &bp.0.data;
+        assert_eq!(d.building_count, 2 * n);
+        assert_eq!(d.header.area_count, 1);
+        assert_eq!((d.header.dragbox_size_x, d.header.dragbox_size_y), (19, 11));
+        assert_eq!((d.areas[0].width, d.areas[0].height), (19, 11));
+        assert_eq!(d.header.cursor_offset_y, 6);
+        for (i, b) in d.buildings.iter().enumerate() {
+            assert_eq!(b.header.index, i as u32);
+        }
+        let (a, b) = (&d.buildings[4].header, &d.buildings[n as usize + 4].header);
+        assert_eq!(a.input_object_index, 12);
+        assert_eq!(b.input_object_index, n + 12);
+        assert_eq!(b.output_object_index, a.output_object_index + n);
+        assert_eq!(b.local_offset_x, a.local_offset_x + 10.0);
+        assert_eq!(b.local_offset_y, a.local_offset_y - 2.0);
+    }
+
+    #[test]
+    fn merge_areas() {
+        let mut bp = EditBlueprint::new(example_station());
+        with_extra_area(&mut bp);
+        bp.0.data.areas[1].index = 2;
+        let mut other = EditBlueprint::new(example_station());
+        with_extra_area(&mut other);
+        with_extra_area(&mut other);
+        let o = &mut other.0.data;
+        (o.areas[1].index, o.areas[2].index, o.areas[2].parent_index) = (3, 5, 3);
+        o.buildings[0].header.area_index = 5;
+        o.buildings[1].header.area_index = 3;
+        let n = bp.0.data.buildings.len();
+        bp.merge(other.0, 10, 0).unwrap();
+
+        let d = &bp.0.data;
+        assert_eq!(d.header.area_count, 4);
+        let areas: Vec<_> = d
+            .areas
+            .iter()
+            .map(|a| (a.index, a.parent_index, a.anchor_local_offset_x))
+            .collect();
+        assert_eq!(areas, [(0, -1, 0), (2, 0, 1), (3, 0, 11), (4, 3, 11)]);
+        assert_eq!(d.buildings[n].header.area_index, 4);
+        assert_eq!(d.buildings[n + 1].header.area_index, 3);
+        assert_eq!(d.buildings[n + 2].header.area_index, 0);
+
+        let mut bp = EditBlueprint::new(example_station());
+        with_extra_area(&mut bp);
+        bp.0.data.areas[1].index = i8::MAX;
+        let mut other = EditBlueprint::new(example_station());
+        with_extra_area(&mut other);
+        assert!(bp.merge(other.0, 10, 0).is_err());
+        assert_eq!(bp.0.data.buildings.len(), n);
+        assert_eq!(bp.0.data.header.dragbox_size_x, 9);
+    }
+
+    #[test]
+    fn crop() {
+        let mut bp = EditBlueprint::new(example_station());
+        assert!(bp.crop(0, 0, 9, 8).is_err());
+        assert!(bp.crop(3, 0, 2, 8).is_err());
+        bp.crop(2, 1, 8, 7).unwrap();
+        let d = &bp.0.data;
+        assert_eq!((d.header.dragbox_size_x, d.header.dragbox_size_y), (7, 7));
+        assert_eq!((d.header.cursor_offset_x, d.header.cursor_offset_y), (2, 3));
+        assert_eq!(d.building_count as usize, d.buildings.len());
+        assert!(d.buildings.len() < 37);
+        for (i, b) in d.buildings.iter().enumerate() {
+            let h = &b.header;
+            assert_eq!(h.index, i as u32);
+            assert!(h.local_offset_x > -0.5 && h.local_offset_y > -0.5);
+            for c in [h.input_object_index, h.output_object_index] {
+                assert!(c == u32::MAX || c < d.building_count);
+            }
+        }
+
+        let parts = EditBlueprint::new(example_station()).split_by_area();
+        assert_eq!(parts.len(), 1);
+        assert_eq!(parts[0].data.buildings.len(), 37);
+        assert_eq!(parts[0].data.header.cursor_offset_x, 4);
+    }
+
+    #[test]
+    fn retain() {
+        let mut bp = EditBlueprint::new(example_station());
+        bp.retain(|b| b.kind() != Ok(DSPItem::InterstellarLogisticsStation));
+        let d = &bp.0.data;
+        assert_eq!(d.building_count, 36);
+        assert_eq!(d.buildings.len(), 36);
+        // Belt 4 was fed by the station, and still goes to belt 24, now 23.
+        let h = &d.buildings[4].header;
+        assert_eq!((h.input_object_index, h.input_from_slot), (u32::MAX, 0));
+        assert_eq!(h.output_object_index, 23);
+    }
 }
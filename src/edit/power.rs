@@ -0,0 +1,103 @@
+use crate::{
+    data::{
+        building::{Building, BuildingParam},
+        enums::BuildingClass,
+        power::{ExchangerMode, PowerUsage, RayReceiverMode, EXCHANGER_POWER},
+        visit::{Visit, Visitor},
+    },
+    power::Power,
+};
+
+#[derive(Default)]
+pub struct GetPower(pub Power);
+
+impl GetPower {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+impl Visitor for GetPower {
+    fn visit_building(&mut self, v: &mut Building) {
+        let Ok(kind) = v.kind() else {
+            return v.visit(self);
+        };
+        let class = BuildingClass::from(kind);
+        let Some(mut p) = PowerUsage::of(kind) else {
+            self.0.add_uncounted(kind);
+            return v.visit(self);
+        };
+        // Charging is set per tick.
+        match &v.param {
+            BuildingParam::Station(s) => {
+                let charging = s.header.work_energy_per_tick as f64 * 60.0;
+                self.0.add_charging(class, charging);
+            }
+            BuildingParam::Distributor(Some(d)) => {
+                let charging = d.work_energy_per_tick as f64 * 60.0;
+                self.0.add_charging(class, charging);
+            }
+            BuildingParam::RayReceiver(Some(r)) => {
+                if r.mode == RayReceiverMode::Photon {
+                    p.generation = 0.0;
+                } else if r.lens.0 != 0 {
+                    p.generation *= 2.0;
+                }
+            }
+            BuildingParam::EnergyExchanger(Some(e)) => match e.mode {
+                ExchangerMode::Charge => p.work = EXCHANGER_POWER,
+                ExchangerMode::Discharge => p.generation = EXCHANGER_POWER,
+                ExchangerMode::Standby => (),
+            },
+            _ => (),
+        }
+        self.0.add(class, p);
+        v.visit(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        data::{
+            enums::{BuildingClass, DSPItem},
+            visit::Visitor,
+        },
+        testutil::{building, example_station},
+    };
+
+    use super::GetPower;
+
+    #[test]
+    fn power() {
+        let mut bp = example_station();
+        let more = [
+            (DSPItem::AssemblingMachineMkI, vec![]),
+            (DSPItem::WindTurbine, vec![]),
+            (DSPItem::RayReceiver, vec![0, DSPItem::GravitonLens as u32]),
+            (DSPItem::EnergyExchanger, vec![1]),
+            (DSPItem::LogisticsDistributor, vec![0, 0, 0, 50_000, 0]),
+            (DSPItem::GaussTurret, vec![]),
+            (DSPItem::GaussTurret, vec![]),
+        ];
+        for (item, params) in more {
+            bp.data.buildings.push(building(item, &params));
+        }
+
+        let mut p = GetPower::new();
+        p.visit_blueprint(&mut bp);
+        let p = p.0;
+        assert_eq!(p.charging, 60e6 + 3e6);
+        assert_eq!(p.uncounted[&DSPItem::GaussTurret], 2);
+        assert_eq!(p.classes[&BuildingClass::Assembler].work, 270e3);
+        assert_eq!(p.classes[&BuildingClass::Assembler].idle, 12e3);
+        let t = p.total();
+        assert_eq!(t.work, 60e6 + 3e6 + 270e3 + 45e6);
+        assert_eq!(t.generation, 300e3 + 30e6);
+        let s = p.to_string();
+        assert!(s.contains("Assembler: 270.00 kW work, 12.00 kW idle, 0 W generated"));
+        assert!(!s.contains("Belt:"));
+        assert!(s.contains("Peak consumption: 108.27 MW"));
+        assert!(s.ends_with("Not counted, power use unknown:\nGaussTurret: 2\n"));
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{
+    data::{
+        building::{Building, BuildingParam},
+        production::{Proliferator, ProliferatorMode},
+        recipe::building_speed,
+        visit::{Visit, Visitor},
+    },
+    rates::Rates,
+};
+
+pub struct GetRates {
+    pub rates: Rates,
+    pub proliferator: Proliferator,
+}
+
+impl GetRates {
+    pub fn new(proliferator: Proliferator) -> Self {
+        Self {
+            rates: Rates::new(),
+            proliferator,
+        }
+    }
+}
+
+impl Visitor for GetRates {
+    fn visit_building(&mut self, v: &mut Building) {
+        let mode = match &v.param {
+            BuildingParam::Production(Some(p)) => Some(p.proliferator_mode),
+            BuildingParam::Lab(Some(l)) if l.research_mode == 0 => Some(l.proliferator_mode),
+            BuildingParam::Lab(_) => None,
+            _ => Some(ProliferatorMode::Speedup),
+        };
+        let (Some(mode), Ok(kind), Ok(recipe)) = (mode, v.kind(), v.recipe()) else {
+            return v.visit(self);
+        };
+        let (Some(speed), Some(info)) = (building_speed(kind), recipe.info()) else {
+            return v.visit(self);
+        };
+
+        let extra = mode == ProliferatorMode::ExtraProducts && info.extra_products;
+        let (speedup, more) = if extra {
+            (0.0, self.proliferator.extra_products())
+        } else {
+            (self.proliferator.speedup(), 0.0)
+        };
+        let crafts = 60.0 * speed * (1.0 + speedup) / info.time;
+        for (i, n) in info.inputs {
+            self.rates.add_consumed(*i, *n as f64 * crafts);
+        }
+        for (i, n) in info.outputs {
+            self.rates
+                .add_produced(*i, *n as f64 * crafts * (1.0 + more));
+        }
+        v.visit(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        data::{
+            enums::{DSPItem, DSPRecipe},
+            production::Proliferator,
+            visit::Visitor,
+        },
+        testutil::{building, example_station},
+    };
+
+    use super::GetRates;
+
+    #[test]
+    fn rates() {
+        let mut bp = example_station();
+        // Extra products, speedup, and a lab doing research.
+        let setup = [
+            (DSPItem::AssemblingMachineMkI, DSPRecipe::Gear, vec![0]),
+            (DSPItem::PlaneSmelter, DSPRecipe::IronIngot, vec![1]),
+            (DSPItem::MatrixLab, DSPRecipe::Gear, vec![1, 1]),
+        ];
+        for (item, recipe, params) in setup {
+            let mut b = building(item, &params);
+            b.header.recipe_id = recipe.into();
+            bp.data.buildings.push(b);
+        }
+
+        let mut r = GetRates::new(Proliferator::None);
+        r.visit_blueprint(&mut bp);
+        assert_eq!(r.rates.produced[&DSPItem::Gear], 45.0);
+        assert_eq!(r.rates.net(DSPItem::IronIngot), 120.0 - 45.0);
+
+        let mut r = GetRates::new(Proliferator::MkIII);
+        r.visit_blueprint(&mut bp);
+        let r = r.rates;
+        assert_eq!(r.produced[&DSPItem::Gear], 56.25);
+        assert_eq!(r.consumed[&DSPItem::IronIngot], 45.0);
+        assert_eq!(r.consumed[&DSPItem::IronOre], 240.0);
+        assert_eq!(r.net(DSPItem::IronIngot), 195.0);
+        assert_eq!(r.produced.len(), 2);
+        assert!(r.to_string().contains("IronIngot: 240.00, 45.00, +195.00"));
+    }
+}
@@ -1,7 +1,9 @@
+use std::collections::HashMap;
+
 use crate::data::{
     building::Building,
     enums::{BPModel, DSPIcon, DSPItem, DSPRecipe},
-    traits::{IconId, ItemId, ItemIdTrait},
+    traits::{BPModelId, IconId, ItemId, ItemIdTrait},
     visit::{Visit, Visitor},
 };
 
@@ -32,6 +34,15 @@ impl<'a> ReplaceItem<'a> {
         ri(self.0, t)
     }
 
+    // Like replace_item, but 0 means "no item" and is left alone.
+    fn replace_filter(&self, t: ItemId<u32>) -> ItemId<u32> {
+        if t == ItemId(0) {
+            t
+        } else {
+            self.replace_item(t)
+        }
+    }
+
     fn replace_icon(&self, t: IconId<u32>) -> IconId<u32> {
         match t.try_into() {
             Ok(DSPIcon::Item(i)) => {
@@ -77,6 +88,54 @@ impl<'a> Visitor for ReplaceItem<'a> {
         v.visit(self)
     }
 
+    fn visit_splitter(&mut self, v: &mut crate::data::splitter::Splitter) {
+        v.filter = self.replace_filter(v.filter);
+        v.visit(self)
+    }
+
+    fn visit_traffic_monitor(&mut self, v: &mut crate::data::monitor::TrafficMonitor) {
+        v.cargo_filter = self.replace_filter(v.cargo_filter);
+        v.alarm_signal = self.replace_icon(v.alarm_signal);
+        v.visit(self)
+    }
+
+    fn visit_fuel_generator(&mut self, v: &mut crate::data::power::FuelGenerator) {
+        v.fuel = self.replace_filter(v.fuel);
+        v.visit(self)
+    }
+
+    fn visit_ray_receiver(&mut self, v: &mut crate::data::power::RayReceiver) {
+        v.lens = self.replace_filter(v.lens);
+        v.visit(self)
+    }
+
+    fn visit_turret(&mut self, v: &mut crate::data::defense::Turret) {
+        v.ammo = self.replace_filter(v.ammo);
+        v.visit(self)
+    }
+
+    fn visit_battle_base(&mut self, v: &mut crate::data::defense::BattleBase) {
+        v.drop_filter = self.replace_filter(v.drop_filter);
+        v.visit(self)
+    }
+
+    fn visit_depot(&mut self, v: &mut crate::data::storage::Depot) {
+        for f in v.filters.iter_mut() {
+            *f = self.replace_filter(*f);
+        }
+        v.visit(self)
+    }
+
+    fn visit_storage_tank(&mut self, v: &mut crate::data::storage::StorageTank) {
+        v.fluid = self.replace_filter(v.fluid);
+        v.visit(self)
+    }
+
+    fn visit_distributor(&mut self, v: &mut crate::data::storage::Distributor) {
+        v.item_id = self.replace_filter(v.item_id);
+        v.visit(self)
+    }
+
     fn visit_building(&mut self, v: &mut crate::data::building::Building) {
         v.header.filter_id = self.replace_item(v.header.filter_id);
         v.visit(self)
@@ -109,7 +168,6 @@ impl<'a> ReplaceRecipe<'a> {
             Ok(_) => (),
             Err(_) => {
                 log::warn!("Unexpected DSP recipe value {}", t.0);
-                ()
             }
         }
     }
@@ -140,22 +198,30 @@ impl<'a> Visitor for ReplaceRecipe<'a> {
         v.visit(self)
     }
 
+    fn visit_traffic_monitor(&mut self, v: &mut crate::data::monitor::TrafficMonitor) {
+        self.replace_icon(&mut v.alarm_signal);
+        v.visit(self)
+    }
+
     fn visit_building(&mut self, v: &mut crate::data::building::Building) {
         self.replace_recipe(&mut v.header.recipe_id);
         v.visit(self)
     }
 }
 
-pub struct ReplaceBuilding<'a>(&'a Replace<DSPItem>);
+// Models we don't know by heart are taken from buildings already in the blueprint.
+pub struct ReplaceBuilding<'a>(&'a Replace<DSPItem>, HashMap<DSPItem, BPModelId<u16>>);
 
 impl<'a> ReplaceBuilding<'a> {
-    pub fn new(f: &'a Replace<DSPItem>) -> Self {
-        Self(f)
+    pub fn new(f: &'a Replace<DSPItem>, known_models: HashMap<DSPItem, BPModelId<u16>>) -> Self {
+        Self(f, known_models)
     }
 
     fn replace_bp_model(&self, b: &mut Building, new: DSPItem) -> anyhow::Result<()> {
-        let new_model = BPModel::from_building(new)?;
-        b.header.model_index = new_model.into();
+        b.header.model_index = match BPModel::from_building(new) {
+            Ok(m) => m.into(),
+            Err(e) => *self.1.get(&new).ok_or(e)?,
+        };
         Ok(())
     }
 
@@ -0,0 +1,58 @@
+use crate::data::{
+    dyson::Ejector,
+    monitor::TrafficMonitor,
+    power::{EnergyExchanger, ExchangerMode, RayReceiver, RayReceiverMode},
+    production::{LabParams, ProductionParams, ProliferatorMode},
+    visit::{Visit, Visitor},
+};
+
+pub struct SetProliferatorMode(pub ProliferatorMode);
+
+impl Visitor for SetProliferatorMode {
+    fn visit_production(&mut self, v: &mut ProductionParams) {
+        v.proliferator_mode = self.0;
+        v.visit(self)
+    }
+
+    fn visit_lab(&mut self, v: &mut LabParams) {
+        v.proliferator_mode = self.0;
+        v.visit(self)
+    }
+}
+
+// Items per minute.
+pub struct SetMonitorFlow(pub f64);
+
+impl Visitor for SetMonitorFlow {
+    fn visit_traffic_monitor(&mut self, v: &mut TrafficMonitor) {
+        v.set_flow_per_minute(self.0);
+        v.visit(self)
+    }
+}
+
+pub struct SetRayReceiverMode(pub RayReceiverMode);
+
+impl Visitor for SetRayReceiverMode {
+    fn visit_ray_receiver(&mut self, v: &mut RayReceiver) {
+        v.mode = self.0;
+        v.visit(self)
+    }
+}
+
+pub struct SetExchangerMode(pub ExchangerMode);
+
+impl Visitor for SetExchangerMode {
+    fn visit_energy_exchanger(&mut self, v: &mut EnergyExchanger) {
+        v.mode = self.0;
+        v.visit(self)
+    }
+}
+
+pub struct SetEjectorOrbit(pub u32);
+
+impl Visitor for SetEjectorOrbit {
+    fn visit_ejector(&mut self, v: &mut Ejector) {
+        v.orbit = self.0;
+        v.visit(self)
+    }
+}
@@ -0,0 +1,353 @@
+use anyhow::Result;
+
+use crate::{
+    data::{
+        enums::DSPItem,
+        station::{
+            SlotDirection, SlotPosition, Station, StationHeader, StationLogic, StationStorage,
+        },
+        traits::{ItemId, TryFromUserString},
+        visit::{Visit, Visitor},
+    },
+    error::some_error,
+};
+
+// Splits "60MW" into (60.0, "mw"). Unit is lowercased and may be empty.
+fn number_and_unit(s: &str) -> Result<(f64, String)> {
+    let s = s.trim();
+    let split = s
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(s.len());
+    let (num, unit) = s.split_at(split);
+    let num: f64 = num
+        .parse()
+        .map_err(|_| some_error(format!("Failed to parse a number from \"{}\"", s)))?;
+    Ok((num, unit.trim().to_lowercase()))
+}
+
+fn unit_error(s: &str, expected: &str) -> anyhow::Error {
+    some_error(format!(
+        "Unexpected unit in \"{}\", expected {}.",
+        s, expected
+    ))
+}
+
+/// Charging power, like "60MW". Plain numbers are in MW.
+pub fn parse_charging_power(s: &str) -> Result<u32> {
+    let (n, unit) = number_and_unit(s)?;
+    let watts = match unit.as_str() {
+        "w" => n,
+        "kw" => n * 1e3,
+        "" | "mw" => n * 1e6,
+        "gw" => n * 1e9,
+        _ => return Err(unit_error(s, "W, kW, MW or GW")),
+    };
+    Ok((watts / 60.0).round() as u32)
+}
+
+/// Drone range angle, like "50deg". Plain numbers are in degrees.
+pub fn parse_drone_range(s: &str) -> Result<u32> {
+    let (n, unit) = number_and_unit(s)?;
+    match unit.as_str() {
+        "" | "deg" | "°" => (),
+        _ => return Err(unit_error(s, "deg")),
+    }
+    if !(0.0..=180.0).contains(&n) || n.fract() != 0.0 {
+        return Err(some_error(format!(
+            "Drone range must be a whole number of degrees up to 180, got \"{}\".",
+            s
+        )));
+    }
+    Ok(StationHeader::angle_to_drone_range(n as usize))
+}
+
+/// Vessel range, like "6ly". Plain numbers are in light years.
+pub fn parse_vessel_range(s: &str) -> Result<u32> {
+    let (n, unit) = number_and_unit(s)?;
+    match unit.as_str() {
+        "" | "ly" => Ok((n * StationHeader::LY as f64).round() as u32),
+        _ => Err(unit_error(s, "ly")),
+    }
+}
+
+/// Warp distance, like "2au". Plain numbers are in AU.
+pub fn parse_warp_distance(s: &str) -> Result<u32> {
+    let (n, unit) = number_and_unit(s)?;
+    match unit.as_str() {
+        "" | "au" => Ok((n * StationHeader::AU as f64).round() as u32),
+        _ => Err(unit_error(s, "au")),
+    }
+}
+
+/// Minimum load, like "20%". Plain numbers are percents too.
+pub fn parse_min_load(s: &str) -> Result<u32> {
+    let (n, unit) = number_and_unit(s)?;
+    match unit.as_str() {
+        "" | "%" if (0.0..=100.0).contains(&n) => Ok(n.round() as u32),
+        "" | "%" => Err(some_error(format!(
+            "Load must be 0 to 100%, got \"{}\".",
+            s
+        ))),
+        _ => Err(unit_error(s, "%")),
+    }
+}
+
+/// Per-ware values to set. None leaves the value alone.
+#[derive(Default)]
+pub struct WareSettings {
+    pub local_logic: Option<StationLogic>,
+    pub remote_logic: Option<StationLogic>,
+    pub max_count: Option<u32>,
+}
+
+impl WareSettings {
+    fn apply(&self, s: &mut StationStorage, is_interstellar: bool) {
+        if let Some(l) = self.local_logic {
+            s.local_logic = l.into();
+        }
+        if let (Some(l), true) = (self.remote_logic, is_interstellar) {
+            s.remote_logic = l.into();
+        }
+        if let Some(m) = self.max_count {
+            s.max_count = m;
+        }
+    }
+}
+
+/// Parses "GravityMatrix:remote=demand,local=supply,max=5000". All keys are optional.
+pub fn parse_ware_settings(s: &str) -> Result<(DSPItem, WareSettings)> {
+    let (item, rest) = s.split_once(':').unwrap_or((s, ""));
+    let item = DSPItem::try_from_user_string(item)?;
+    let mut settings = WareSettings::default();
+    for kv in rest.split(',').filter(|kv| !kv.is_empty()) {
+        let (k, v) = kv.split_once('=').ok_or_else(|| {
+            some_error(format!(
+                "Invalid ware setting \"{}\", expected key=value.",
+                kv
+            ))
+        })?;
+        match k {
+            "local" => settings.local_logic = Some(StationLogic::try_from_user_string(v)?),
+            "remote" => settings.remote_logic = Some(StationLogic::try_from_user_string(v)?),
+            "max" => {
+                settings.max_count = Some(
+                    v.parse()
+                        .map_err(|_| some_error(format!("Failed to parse max count \"{}\".", v)))?,
+                )
+            }
+            _ => {
+                return Err(some_error(format!(
+                    "Unknown ware setting \"{}\". Known settings: local, remote, max.",
+                    k
+                )))
+            }
+        }
+    }
+    Ok((item, settings))
+}
+
+/// Parses "north-left=out:GravityMatrix". Ware may be left out, like in "north-left=none".
+pub fn parse_slot_route(s: &str) -> Result<(SlotPosition, SlotDirection, Option<DSPItem>)> {
+    let (pos, route) = s.split_once('=').ok_or_else(|| {
+        some_error(format!(
+            "Invalid slot route \"{}\", expected position=direction:Item.",
+            s
+        ))
+    })?;
+    let (dir, ware) = match route.split_once(':') {
+        Some((d, w)) => (d, Some(DSPItem::try_from_user_string(w)?)),
+        None => (route, None),
+    };
+    Ok((
+        SlotPosition::try_from_user_string(pos)?,
+        SlotDirection::try_from_user_string(dir)?,
+        ware,
+    ))
+}
+
+/// Header values to set, in game units. None leaves the value alone.
+#[derive(Default)]
+pub struct StationSettings {
+    pub work_energy_per_tick: Option<u32>,
+    pub drone_range: Option<u32>,
+    pub vessel_range: Option<u32>,
+    pub orbital_collector: Option<bool>,
+    pub warp_distance: Option<u32>,
+    pub equip_warper: Option<bool>,
+    pub drone_min_capacity: Option<u32>,
+    pub vessel_min_capacity: Option<u32>,
+    pub piler_count: Option<u32>,
+}
+
+impl StationSettings {
+    fn apply(&self, h: &mut StationHeader, is_interstellar: bool) {
+        fn set(to: &mut u32, from: Option<u32>) {
+            if let Some(v) = from {
+                *to = v;
+            }
+        }
+        set(&mut h.work_energy_per_tick, self.work_energy_per_tick);
+        set(&mut h.drone_range, self.drone_range);
+        set(&mut h.drone_min_capacity, self.drone_min_capacity);
+        set(&mut h.piler_count, self.piler_count);
+        if !is_interstellar {
+            return;
+        }
+        set(&mut h.vessel_range, self.vessel_range);
+        set(
+            &mut h.orbital_collector,
+            self.orbital_collector.map(u32::from),
+        );
+        set(&mut h.warp_distance, self.warp_distance);
+        set(&mut h.equip_warper, self.equip_warper.map(u32::from));
+        set(&mut h.vessel_min_capacity, self.vessel_min_capacity);
+    }
+}
+
+fn station_has_ware(s: &Station, ware: DSPItem) -> bool {
+    s.valid_storage()
+        .iter()
+        .any(|s| s.item_id == ItemId::from(ware))
+}
+
+pub struct SetStationSettings<'a> {
+    settings: &'a StationSettings,
+    ware: Option<DSPItem>,
+}
+
+impl<'a> SetStationSettings<'a> {
+    /// If ware is set, only stations that have it in storage are changed.
+    pub fn new(settings: &'a StationSettings, ware: Option<DSPItem>) -> Self {
+        Self { settings, ware }
+    }
+}
+
+impl<'a> Visitor for SetStationSettings<'a> {
+    fn visit_station(&mut self, v: &mut Station) {
+        if self.ware.is_none_or(|w| station_has_ware(v, w)) {
+            self.settings.apply(&mut v.header, v.is_interstellar);
+        }
+        v.visit(self)
+    }
+}
+
+pub struct SetWareSettings<'a> {
+    ware: DSPItem,
+    settings: &'a WareSettings,
+    station_ware: Option<DSPItem>,
+}
+
+impl<'a> SetWareSettings<'a> {
+    /// If station_ware is set, only stations that have it in storage are changed.
+    pub fn new(ware: DSPItem, settings: &'a WareSettings, station_ware: Option<DSPItem>) -> Self {
+        Self {
+            ware,
+            settings,
+            station_ware,
+        }
+    }
+}
+
+impl<'a> Visitor for SetWareSettings<'a> {
+    fn visit_station(&mut self, v: &mut Station) {
+        if self.station_ware.is_none_or(|w| station_has_ware(v, w)) {
+            let is_interstellar = v.is_interstellar;
+            let ware = ItemId::from(self.ware);
+            for s in v
+                .valid_storage_mut()
+                .iter_mut()
+                .filter(|s| s.item_id == ware)
+            {
+                self.settings.apply(s, is_interstellar);
+            }
+        }
+        v.visit(self)
+    }
+}
+
+pub struct SetStationSlot {
+    pos: SlotPosition,
+    direction: SlotDirection,
+    ware: Option<DSPItem>,
+    station_ware: Option<DSPItem>,
+    pub result: Result<()>,
+}
+
+impl SetStationSlot {
+    /// If station_ware is set, only stations that have it in storage are changed.
+    pub fn new(
+        pos: SlotPosition,
+        direction: SlotDirection,
+        ware: Option<DSPItem>,
+        station_ware: Option<DSPItem>,
+    ) -> Self {
+        Self {
+            pos,
+            direction,
+            ware,
+            station_ware,
+            result: Ok(()),
+        }
+    }
+}
+
+impl Visitor for SetStationSlot {
+    fn visit_station(&mut self, v: &mut Station) {
+        if self.result.is_ok() && self.station_ware.is_none_or(|w| station_has_ware(v, w)) {
+            self.result = v.set_slot(self.pos, self.direction, self.ware);
+        }
+        v.visit(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn units() {
+        assert_eq!(parse_charging_power("60MW").unwrap(), 1_000_000);
+        assert_eq!(parse_charging_power("300 kW").unwrap(), 5_000);
+        assert_eq!(
+            parse_drone_range("50deg").unwrap(),
+            StationHeader::angle_to_drone_range(50)
+        );
+        assert_eq!(parse_vessel_range("6ly").unwrap(), 144_000);
+        assert_eq!(parse_warp_distance("0.5AU").unwrap(), 20_000);
+        assert_eq!(parse_min_load("40%").unwrap(), 40);
+        assert!(parse_vessel_range("6au").is_err());
+        assert!(parse_min_load("120%").is_err());
+        assert!(parse_drone_range("lots").is_err());
+        assert_eq!(parse_drone_range("90").unwrap(), 0);
+        assert_eq!(parse_drone_range("150").unwrap() as i32, -86_602_540);
+        assert_eq!(parse_drone_range("180").unwrap() as i32, -100_000_000);
+        assert!(parse_drone_range("181").is_err());
+    }
+
+    #[test]
+    fn slot_route() {
+        let (pos, dir, ware) = parse_slot_route("north-left=out:GravityMatrix").unwrap();
+        assert_eq!(pos, SlotPosition::NorthLeft);
+        assert_eq!(dir, SlotDirection::Output);
+        assert_eq!(ware, Some(DSPItem::GravityMatrix));
+        let (pos, dir, ware) = parse_slot_route("east-center=none").unwrap();
+        assert_eq!(pos, SlotPosition::EastCenter);
+        assert_eq!(dir, SlotDirection::None);
+        assert_eq!(ware, None);
+        assert!(parse_slot_route("north-middle=in").is_err());
+        assert!(parse_slot_route("north-left").is_err());
+    }
+
+    #[test]
+    fn ware_settings() {
+        let (item, w) = parse_ware_settings("GravityMatrix:remote=demand,max=5000").unwrap();
+        assert_eq!(item, DSPItem::GravityMatrix);
+        assert_eq!(w.local_logic, None);
+        assert_eq!(w.remote_logic, Some(StationLogic::Demand));
+        assert_eq!(w.max_count, Some(5000));
+        assert!(parse_ware_settings("GravityMatrix").is_ok());
+        assert!(parse_ware_settings("GravityMatrix:local=sell").is_err());
+        assert!(parse_ware_settings("GravityMatrix:min=1").is_err());
+        assert!(parse_ware_settings("Gravity:local=supply").is_err());
+    }
+}
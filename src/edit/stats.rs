@@ -1,8 +1,12 @@
 use crate::{
-    data::visit::{Visit, Visitor},
+    data::{
+        enums::DSPItem,
+        visit::{Visit, Visitor},
+    },
     stats::Stats,
 };
 
+#[derive(Default)]
 pub struct GetStats(pub Stats);
 
 impl GetStats {
@@ -22,6 +26,34 @@ impl Visitor for GetStats {
         v.visit(self)
     }
 
+    fn visit_depot(&mut self, v: &mut crate::data::storage::Depot) {
+        let mut items: Vec<DSPItem> = v
+            .filters
+            .iter()
+            .filter_map(|f| (*f).try_into().ok())
+            .collect();
+        items.sort_by_key(|i| *i as u16);
+        items.dedup();
+        for i in items {
+            self.0.add_storage_content(i);
+        }
+        v.visit(self)
+    }
+
+    fn visit_storage_tank(&mut self, v: &mut crate::data::storage::StorageTank) {
+        if let Ok(b) = v.fluid.try_into() {
+            self.0.add_storage_content(b);
+        }
+        v.visit(self)
+    }
+
+    fn visit_distributor(&mut self, v: &mut crate::data::storage::Distributor) {
+        if let Ok(b) = v.item_id.try_into() {
+            self.0.add_storage_content(b);
+        }
+        v.visit(self)
+    }
+
     fn visit_station_storage(&mut self, v: &mut crate::data::station::StationStorage) {
         if let Ok(b) = v.item_id.try_into() {
             self.0.add_station_ware(b);
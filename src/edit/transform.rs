@@ -0,0 +1,234 @@
+use std::collections::HashMap;
+
+use strum::{AsRefStr, EnumIter, EnumString};
+
+use crate::data::{
+    area::Area,
+    blueprint::BlueprintData,
+    building::Building,
+    enums::DSPItem,
+    station::Station,
+    traits::{try_from_user_string_listed, TryFromUserString},
+    visit::{Visit, Visitor},
+};
+
+/// Moves buildings relative to the paste cursor, in grid cells. Z is height, one belt layer is
+/// about 1.33.
+pub struct Translate {
+    pub dx: f32,
+    pub dy: f32,
+    pub dz: f32,
+}
+
+impl Visitor for Translate {
+    fn visit_building(&mut self, v: &mut Building) {
+        let h = &mut v.header;
+        h.local_offset_x += self.dx;
+        h.local_offset_y += self.dy;
+        h.local_offset_z += self.dz;
+        h.local_offset_x2 += self.dx;
+        h.local_offset_y2 += self.dy;
+        h.local_offset_z2 += self.dz;
+        v.visit(self)
+    }
+}
+
+// Yaw is in degrees, clockwise from north.
+fn norm_yaw(yaw: f32) -> f32 {
+    yaw.rem_euclid(360.0)
+}
+
+// Cell `i` counted from the other end of a box `size` cells long. Cells outside the box are
+// kept inside it, callers check for them first.
+fn flip_cell(i: u32, size: u32) -> u32 {
+    size.saturating_sub(1).saturating_sub(i)
+}
+
+// Corner of a span `len` cells long starting at `start`, counted from the other end.
+fn flip_span(start: u16, len: u16, size: u32) -> u16 {
+    (size as u16).saturating_sub(start).saturating_sub(len)
+}
+
+/// One clockwise quarter turn within the drag box. Building offsets are cell indices, so a box
+/// of width w spans 0 to w - 1.
+pub struct RotateQuarter {
+    w: u32,
+}
+
+impl RotateQuarter {
+    pub fn new() -> Self {
+        Self { w: 0 }
+    }
+
+    fn point(&self, x: f32, y: f32) -> (f32, f32) {
+        (y, self.w as f32 - 1.0 - x)
+    }
+
+    fn area(&self, a: &mut Area) {
+        (a.anchor_local_offset_x, a.anchor_local_offset_y) = (
+            a.anchor_local_offset_y,
+            flip_span(a.anchor_local_offset_x, a.width, self.w),
+        );
+        std::mem::swap(&mut a.width, &mut a.height);
+    }
+}
+
+impl Default for RotateQuarter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Visitor for RotateQuarter {
+    fn visit_blueprint_data(&mut self, v: &mut BlueprintData) {
+        let h = &mut v.header;
+        self.w = h.dragbox_size_x;
+        (h.cursor_offset_x, h.cursor_offset_y) =
+            (h.cursor_offset_y, flip_cell(h.cursor_offset_x, self.w));
+        std::mem::swap(&mut h.dragbox_size_x, &mut h.dragbox_size_y);
+        for a in v.areas.iter_mut() {
+            self.area(a);
+        }
+        v.visit(self)
+    }
+
+    fn visit_building(&mut self, v: &mut Building) {
+        let h = &mut v.header;
+        (h.local_offset_x, h.local_offset_y) = self.point(h.local_offset_x, h.local_offset_y);
+        (h.local_offset_x2, h.local_offset_y2) = self.point(h.local_offset_x2, h.local_offset_y2);
+        h.yaw = norm_yaw(h.yaw + 90.0);
+        h.yaw2 = norm_yaw(h.yaw2 + 90.0);
+        v.visit(self)
+    }
+}
+
+#[derive(EnumString, EnumIter, AsRefStr, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Axis {
+    /// Flips west and east.
+    #[strum(serialize = "x")]
+    X,
+    /// Flips north and south.
+    #[strum(serialize = "y")]
+    Y,
+}
+
+impl TryFromUserString for Axis {
+    fn try_from_user_string(s: &str) -> anyhow::Result<Self> {
+        try_from_user_string_listed(s, "axis")
+    }
+}
+
+// Any mirror is a west-east flip in the building's own frame, plus a rotation. So slots
+// get the same west-east flip whatever the axis.
+
+// Slots in SlotPosition order. Left and right swap, west and east swap.
+const STATION_SLOT_MIRROR: [i8; 12] = [2, 1, 0, 11, 10, 9, 8, 7, 6, 5, 4, 3];
+// North, east, south, west.
+const SPLITTER_SLOT_MIRROR: [i8; 4] = [0, 3, 2, 1];
+
+fn slot_mirror(kind: Option<&DSPItem>) -> Option<&'static [i8]> {
+    match kind {
+        Some(k) if k.is_station() => Some(&STATION_SLOT_MIRROR),
+        Some(k) if k.is_splitter() => Some(&SPLITTER_SLOT_MIRROR),
+        _ => None,
+    }
+}
+
+fn mirror_slot(slot: &mut i8, map: Option<&[i8]>) {
+    if let Some(&s) = map.and_then(|m| m.get(usize::try_from(*slot).ok()?)) {
+        *slot = s;
+    }
+}
+
+pub struct Mirror {
+    axis: Axis,
+    w: u32,
+    h: u32,
+    kinds: HashMap<u32, DSPItem>,
+}
+
+impl Mirror {
+    pub fn new(axis: Axis) -> Self {
+        Self {
+            axis,
+            w: 0,
+            h: 0,
+            kinds: HashMap::new(),
+        }
+    }
+
+    fn point(&self, x: f32, y: f32) -> (f32, f32) {
+        match self.axis {
+            Axis::X => (self.w as f32 - 1.0 - x, y),
+            Axis::Y => (x, self.h as f32 - 1.0 - y),
+        }
+    }
+
+    fn cell(&self, x: u32, y: u32) -> (u32, u32) {
+        match self.axis {
+            Axis::X => (flip_cell(x, self.w), y),
+            Axis::Y => (x, flip_cell(y, self.h)),
+        }
+    }
+
+    fn area(&self, a: &mut Area) {
+        match self.axis {
+            Axis::X => {
+                a.anchor_local_offset_x = flip_span(a.anchor_local_offset_x, a.width, self.w)
+            }
+            Axis::Y => {
+                a.anchor_local_offset_y = flip_span(a.anchor_local_offset_y, a.height, self.h)
+            }
+        }
+    }
+
+    fn yaw(&self, yaw: f32) -> f32 {
+        match self.axis {
+            Axis::X => norm_yaw(-yaw),
+            Axis::Y => norm_yaw(180.0 - yaw),
+        }
+    }
+}
+
+impl Visitor for Mirror {
+    fn visit_blueprint_data(&mut self, v: &mut BlueprintData) {
+        let h = &mut v.header;
+        self.w = h.dragbox_size_x;
+        self.h = h.dragbox_size_y;
+        (h.cursor_offset_x, h.cursor_offset_y) = self.cell(h.cursor_offset_x, h.cursor_offset_y);
+        for a in v.areas.iter_mut() {
+            self.area(a);
+        }
+        self.kinds = v
+            .buildings
+            .iter()
+            .filter_map(|b| Some((b.header.index, b.kind().ok()?)))
+            .collect();
+        v.visit(self)
+    }
+
+    fn visit_building(&mut self, v: &mut Building) {
+        let own = slot_mirror(v.kind().ok().as_ref());
+        let to = slot_mirror(self.kinds.get(&v.header.output_object_index));
+        let from = slot_mirror(self.kinds.get(&v.header.input_object_index));
+        let h = &mut v.header;
+        (h.local_offset_x, h.local_offset_y) = self.point(h.local_offset_x, h.local_offset_y);
+        (h.local_offset_x2, h.local_offset_y2) = self.point(h.local_offset_x2, h.local_offset_y2);
+        h.yaw = self.yaw(h.yaw);
+        h.yaw2 = self.yaw(h.yaw2);
+        mirror_slot(&mut h.output_from_slot, own);
+        mirror_slot(&mut h.input_to_slot, own);
+        mirror_slot(&mut h.output_to_slot, to);
+        mirror_slot(&mut h.input_from_slot, from);
+        v.visit(self)
+    }
+
+    fn visit_station(&mut self, v: &mut Station) {
+        let mut slots: Vec<_> = v.slots.drain(..).map(Some).collect();
+        v.slots = STATION_SLOT_MIRROR
+            .iter()
+            .map(|&i| slots[i as usize].take().unwrap())
+            .collect();
+        v.visit(self)
+    }
+}
@@ -0,0 +1,390 @@
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    io::Write,
+};
+
+use strum::{AsRefStr, EnumIter, EnumString};
+
+use crate::{
+    data::{
+        blueprint::BlueprintData,
+        building::{Building, BuildingHeader},
+        enums::{BuildingClass, DSPItem, DSPRecipe},
+        traits::{try_from_user_string_listed, TryFromUserString},
+    },
+    locale::LocalizedEnumImpl,
+};
+
+/// Building index meaning "not connected".
+pub const NO_BUILDING: u32 = u32::MAX;
+
+pub struct Node {
+    pub index: u32,
+    pub item: Option<DSPItem>,
+    pub recipe: Option<DSPRecipe>,
+}
+
+impl Node {
+    pub fn class(&self) -> Option<BuildingClass> {
+        self.item.map(BuildingClass::from)
+    }
+
+    /// Building name, and recipe name if it has one. Localized if a locale is set.
+    pub fn label(&self) -> String {
+        let item = match &self.item {
+            Some(i) => i.localize().unwrap_or(i.as_ref()),
+            None => "Unknown building",
+        };
+        match &self.recipe {
+            Some(r) => format!("{}\n{}", item, r.localize().unwrap_or(r.as_ref())),
+            None => item.to_owned(),
+        }
+    }
+}
+
+#[derive(EnumString, EnumIter, AsRefStr, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum GraphFormat {
+    #[strum(serialize = "dot")]
+    Dot,
+    #[strum(serialize = "graphml")]
+    GraphMl,
+}
+
+impl TryFromUserString for GraphFormat {
+    fn try_from_user_string(s: &str) -> anyhow::Result<Self> {
+        try_from_user_string_listed(s, "graph format")
+    }
+}
+
+fn dot_escape(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+pub(crate) fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Items flow from `from` to `to`. Slots are those of the building headers; what they mean
+/// depends on the building.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Link {
+    pub from: u32,
+    pub to: u32,
+    pub from_slot: i8,
+    pub to_slot: i8,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum End {
+    Input,
+    Output,
+}
+
+/// A connection of `building` pointing at `target`, which is not in the blueprint.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct BrokenLink {
+    pub building: u32,
+    pub end: End,
+    pub target: u32,
+}
+
+/// Buildings and the links between them, from the connection fields of building headers.
+pub struct Graph {
+    pub nodes: BTreeMap<u32, Node>,
+    pub links: Vec<Link>,
+    pub broken: Vec<BrokenLink>,
+    downstream: HashMap<u32, Vec<usize>>,
+    upstream: HashMap<u32, Vec<usize>>,
+}
+
+impl Graph {
+    pub fn new(data: &BlueprintData) -> Self {
+        let nodes: BTreeMap<u32, Node> = data
+            .buildings
+            .iter()
+            .map(|b| {
+                let node = Node {
+                    index: b.header.index,
+                    item: b.kind().ok(),
+                    recipe: b.recipe().ok(),
+                };
+                (b.header.index, node)
+            })
+            .collect();
+
+        // A sorter and the belt it takes from may both record the same link.
+        let mut seen = HashSet::new();
+        let mut links = vec![];
+        let mut broken = vec![];
+        for b in data.buildings.iter() {
+            let h = &b.header;
+            let ends = [
+                (
+                    End::Output,
+                    h.output_object_index,
+                    Link {
+                        from: h.index,
+                        to: h.output_object_index,
+                        from_slot: h.output_from_slot,
+                        to_slot: h.output_to_slot,
+                    },
+                ),
+                (
+                    End::Input,
+                    h.input_object_index,
+                    Link {
+                        from: h.input_object_index,
+                        to: h.index,
+                        from_slot: h.input_from_slot,
+                        to_slot: h.input_to_slot,
+                    },
+                ),
+            ];
+            for (end, target, link) in ends {
+                if target == NO_BUILDING {
+                    continue;
+                }
+                if !nodes.contains_key(&target) {
+                    broken.push(BrokenLink {
+                        building: h.index,
+                        end,
+                        target,
+                    });
+                    continue;
+                }
+                if seen.insert(link) {
+                    links.push(link);
+                }
+            }
+        }
+
+        let mut downstream: HashMap<u32, Vec<usize>> = HashMap::new();
+        let mut upstream: HashMap<u32, Vec<usize>> = HashMap::new();
+        for (i, l) in links.iter().enumerate() {
+            downstream.entry(l.from).or_default().push(i);
+            upstream.entry(l.to).or_default().push(i);
+        }
+
+        Self {
+            nodes,
+            links,
+            broken,
+            downstream,
+            upstream,
+        }
+    }
+
+    fn links_of<'a>(
+        &'a self,
+        map: &'a HashMap<u32, Vec<usize>>,
+        index: u32,
+    ) -> impl Iterator<Item = &'a Link> + 'a {
+        map.get(&index)
+            .into_iter()
+            .flatten()
+            .map(|&i| &self.links[i])
+    }
+
+    /// Links items leave `index` by.
+    pub fn outputs(&self, index: u32) -> impl Iterator<Item = &Link> + '_ {
+        self.links_of(&self.downstream, index)
+    }
+
+    /// Links items arrive at `index` by.
+    pub fn inputs(&self, index: u32) -> impl Iterator<Item = &Link> + '_ {
+        self.links_of(&self.upstream, index)
+    }
+
+    fn reachable(&self, index: u32, down: bool) -> BTreeSet<u32> {
+        let mut found = BTreeSet::new();
+        let mut todo = vec![index];
+        while let Some(i) = todo.pop() {
+            let next: Vec<u32> = if down {
+                self.outputs(i).map(|l| l.to).collect()
+            } else {
+                self.inputs(i).map(|l| l.from).collect()
+            };
+            for n in next {
+                if n != index && found.insert(n) {
+                    todo.push(n);
+                }
+            }
+        }
+        found
+    }
+
+    /// All buildings items from `index` can get to.
+    pub fn downstream(&self, index: u32) -> BTreeSet<u32> {
+        self.reachable(index, true)
+    }
+
+    /// All buildings items can get to `index` from.
+    pub fn upstream(&self, index: u32) -> BTreeSet<u32> {
+        self.reachable(index, false)
+    }
+
+    pub fn write<W: Write>(&self, w: &mut W, format: GraphFormat) -> std::io::Result<()> {
+        match format {
+            GraphFormat::Dot => self.write_dot(w),
+            GraphFormat::GraphMl => self.write_graphml(w),
+        }
+    }
+
+    fn write_dot<W: Write>(&self, w: &mut W) -> std::io::Result<()> {
+        writeln!(w, "digraph blueprint {{")?;
+        for n in self.nodes.values() {
+            writeln!(w, "  b{} [label=\"{}\"];", n.index, dot_escape(&n.label()))?;
+        }
+        for l in self.links.iter() {
+            writeln!(
+                w,
+                "  b{} -> b{} [taillabel=\"{}\", headlabel=\"{}\"];",
+                l.from, l.to, l.from_slot, l.to_slot
+            )?;
+        }
+        writeln!(w, "}}")
+    }
+
+    fn write_graphml<W: Write>(&self, w: &mut W) -> std::io::Result<()> {
+        writeln!(w, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(
+            w,
+            r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">"#
+        )?;
+        writeln!(
+            w,
+            r#"  <key id="label" for="node" attr.name="label" attr.type="string"/>"#
+        )?;
+        writeln!(
+            w,
+            r#"  <key id="from_slot" for="edge" attr.name="from_slot" attr.type="int"/>"#
+        )?;
+        writeln!(
+            w,
+            r#"  <key id="to_slot" for="edge" attr.name="to_slot" attr.type="int"/>"#
+        )?;
+        writeln!(w, r#"  <graph id="blueprint" edgedefault="directed">"#)?;
+        for n in self.nodes.values() {
+            writeln!(
+                w,
+                r#"    <node id="b{}"><data key="label">{}</data></node>"#,
+                n.index,
+                xml_escape(&n.label())
+            )?;
+        }
+        for l in self.links.iter() {
+            writeln!(
+                w,
+                r#"    <edge source="b{}" target="b{}"><data key="from_slot">{}</data><data key="to_slot">{}</data></edge>"#,
+                l.from, l.to, l.from_slot, l.to_slot
+            )?;
+        }
+        writeln!(w, "  </graph>")?;
+        writeln!(w, "</graphml>")
+    }
+}
+
+fn clear_output(h: &mut BuildingHeader) {
+    h.output_object_index = NO_BUILDING;
+    h.output_to_slot = 0;
+}
+
+fn clear_input(h: &mut BuildingHeader) {
+    h.input_object_index = NO_BUILDING;
+    h.input_from_slot = 0;
+}
+
+/// Rewrites building indices through `map`. Connections to buildings missing from the map are
+/// cleared.
+pub fn rewrite_indices(buildings: &mut [Building], map: &HashMap<u32, u32>) {
+    for b in buildings.iter_mut() {
+        let h = &mut b.header;
+        if let Some(&i) = map.get(&h.index) {
+            h.index = i;
+        }
+        match map.get(&h.output_object_index) {
+            Some(&i) => h.output_object_index = i,
+            None => clear_output(h),
+        }
+        match map.get(&h.input_object_index) {
+            Some(&i) => h.input_object_index = i,
+            None => clear_input(h),
+        }
+    }
+}
+
+/// Numbers buildings in order, starting at 0, and fixes the building count. Connections to
+/// buildings no longer in the blueprint are cleared.
+pub fn renumber(data: &mut BlueprintData) {
+    let map: HashMap<u32, u32> = data
+        .buildings
+        .iter()
+        .enumerate()
+        .map(|(i, b)| (b.header.index, i as u32))
+        .collect();
+    rewrite_indices(&mut data.buildings, &map);
+    data.building_count = data.buildings.len() as u32;
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{data::enums::DSPItem, testutil::example_station};
+
+    use super::{renumber, BrokenLink, End, Graph, GraphFormat};
+
+    #[test]
+    fn example_links() {
+        let mut bp = example_station();
+        let g = Graph::new(&bp.data);
+        assert_eq!(g.nodes.len(), 37);
+        assert!(g.broken.is_empty());
+        assert_eq!(
+            g.nodes[&12].item,
+            Some(DSPItem::InterstellarLogisticsStation)
+        );
+        // Belt 4 takes from the south center slot and goes on to belt 24, then 17.
+        let out: Vec<_> = g.outputs(12).map(|l| l.to).collect();
+        assert!(out.contains(&4));
+        let l = g.inputs(4).next().unwrap();
+        assert_eq!((l.from, l.from_slot), (12, 7));
+        assert_eq!(g.outputs(4).next().unwrap().to, 24);
+        assert!(g.downstream(4).contains(&17));
+        assert!(g.upstream(24).contains(&12));
+        assert!(!g.upstream(12).contains(&12));
+
+        bp.data.buildings.remove(24);
+        let g = Graph::new(&bp.data);
+        assert_eq!(
+            g.broken,
+            vec![BrokenLink {
+                building: 4,
+                end: End::Output,
+                target: 24
+            }]
+        );
+        renumber(&mut bp.data);
+        assert!(Graph::new(&bp.data).broken.is_empty());
+        assert_eq!(bp.data.building_count, 36);
+    }
+
+    #[test]
+    fn export() {
+        let g = Graph::new(&example_station().data);
+        let mut dot = vec![];
+        g.write(&mut dot, GraphFormat::Dot).unwrap();
+        let dot = String::from_utf8(dot).unwrap();
+        assert!(dot.contains("b12 [label=\"InterstellarLogisticsStation\"];"));
+        assert!(dot.contains("b12 -> b4 [taillabel=\"7\", headlabel=\"1\"];"));
+
+        let mut xml = vec![];
+        g.write(&mut xml, GraphFormat::GraphMl).unwrap();
+        let xml = String::from_utf8(xml).unwrap();
+        assert_eq!(xml.matches("<node ").count(), 37);
+        assert_eq!(xml.matches("<edge ").count(), g.links.len());
+    }
+}
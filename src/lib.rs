@@ -1,32 +1,61 @@
 use args::Commands;
 use blueprint::Blueprint;
+use bom::{BillOfMaterials, BomFormat};
 use clap::Parser;
 use data::{
-    enums::{DSPItem, DSPRecipe},
+    enums::{BuildingClass, DSPItem, DSPRecipe},
+    power::{ExchangerMode, RayReceiverMode},
+    production::{Proliferator, ProliferatorMode},
     traits::{DSPEnum, TryFromUserString},
 };
-use edit::EditBlueprint;
+use edit::{
+    station::{
+        parse_charging_power, parse_drone_range, parse_min_load, parse_slot_route,
+        parse_vessel_range, parse_ware_settings, parse_warp_distance, StationSettings,
+    },
+    transform::Axis,
+    EditBlueprint,
+};
 use error::some_error;
+use graph::{Graph, GraphFormat};
 use locale::{Locale, GLOBAL_SERIALIZATION_LOCALE};
+use render::{
+    jpeg::write_jpeg,
+    png::write_png,
+    raster::{rasterize, MAX_SIZE},
+    svg::write_svg,
+    thumbnail_path, Scene,
+};
 use std::{
     collections::HashMap,
     fs::File,
     io::{Cursor, Read, Seek, Stdout, Write},
+    path::{Path, PathBuf},
+    str::FromStr,
 };
 use strum::IntoEnumIterator;
 
-use crate::{data::visit::Visitor, edit::stats::GetStats};
+use crate::{
+    data::visit::Visitor,
+    edit::{power::GetPower, rates::GetRates, stats::GetStats},
+};
 
 pub(crate) mod args;
-pub(crate) mod blueprint;
-pub(crate) mod data;
-pub(crate) mod edit;
-pub(crate) mod error;
+pub mod blueprint;
+pub mod bom;
+pub mod check;
+pub mod data;
+pub mod edit;
+pub mod error;
+pub mod graph;
 pub(crate) mod locale;
-pub(crate) mod md5;
+pub mod md5;
+pub mod power;
 #[cfg(feature = "python")]
 pub(crate) mod python;
-pub(crate) mod stats;
+pub mod rates;
+pub mod render;
+pub mod stats;
 #[cfg(test)]
 pub(crate) mod testutil;
 
@@ -101,6 +130,22 @@ fn parse_comma_list(s: &str) -> anyhow::Result<Vec<(String, String)>> {
         .collect()
 }
 
+fn parse_number_list<T: FromStr>(s: &str, what: &str) -> anyhow::Result<Vec<T>> {
+    s.split(',')
+        .map(|v| {
+            v.trim().parse().map_err(|_| {
+                some_error(format!("Invalid number \"{}\" in {}: \"{}\".", v, what, s))
+            })
+        })
+        .collect()
+}
+
+fn parse_user_list<T: TryFromUserString>(s: &str) -> anyhow::Result<Vec<T>> {
+    s.split(',')
+        .map(|v| T::try_from_user_string(v.trim()))
+        .collect()
+}
+
 fn parse_into_enum_map<T: DSPEnum + 'static>(s: &str) -> anyhow::Result<HashMap<T, T>> {
     let l = parse_comma_list(s)?;
     let mut map = HashMap::new();
@@ -219,9 +264,307 @@ pub fn cmdline() -> anyhow::Result<()> {
             if let Some(i) = eargs.icon_text {
                 bp.set_icon_text(&i);
             }
+            if let Some(m) = eargs.proliferator_mode {
+                bp.set_proliferator_mode(ProliferatorMode::try_from_user_string(&m)?);
+            }
+            if let Some(f) = eargs.monitor_flow {
+                if f.is_nan() || f < 0.0 {
+                    return Err(some_error("Monitor flow must not be negative."));
+                }
+                bp.set_monitor_flow(f);
+            }
+            if let Some(m) = eargs.receiver_mode {
+                bp.set_ray_receiver_mode(RayReceiverMode::try_from_user_string(&m)?);
+            }
+            if let Some(m) = eargs.exchanger_mode {
+                bp.set_exchanger_mode(ExchangerMode::try_from_user_string(&m)?);
+            }
+            if let Some(o) = eargs.ejector_orbit {
+                bp.set_ejector_orbit(o);
+            }
+            output.write_all(bp.0.into_bp_string(args.compression_level)?.as_bytes())?;
+            output.flush_if_stdout()?;
+        }
+        Commands::Station(sargs) => {
+            let mut input = input()?;
+            let mut output = output()?;
+            let mut bp = EditBlueprint::new(itob(&mut input)?);
+
+            let opt = |s: Option<String>, f: fn(&str) -> anyhow::Result<u32>| {
+                s.as_deref().map(f).transpose()
+            };
+            let settings = StationSettings {
+                work_energy_per_tick: opt(sargs.charging, parse_charging_power)?,
+                drone_range: opt(sargs.drone_range, parse_drone_range)?,
+                vessel_range: opt(sargs.vessel_range, parse_vessel_range)?,
+                orbital_collector: sargs.orbital_collector,
+                warp_distance: opt(sargs.warp, parse_warp_distance)?,
+                equip_warper: sargs.equip_warper,
+                drone_min_capacity: opt(sargs.drone_min_load, parse_min_load)?,
+                vessel_min_capacity: opt(sargs.vessel_min_load, parse_min_load)?,
+                piler_count: sargs.piler_count,
+            };
+            let ware = sargs
+                .with_ware
+                .map(|w| DSPItem::try_from_user_string(&w))
+                .transpose()?;
+            bp.set_station_settings(&settings, ware);
+            for w in sargs.ware {
+                let (item, ware_settings) = parse_ware_settings(&w)?;
+                bp.set_ware_settings(item, &ware_settings, ware);
+            }
+            for s in sargs.slot {
+                let (pos, dir, slot_ware) = parse_slot_route(&s)?;
+                bp.set_station_slot(pos, dir, slot_ware, ware)?;
+            }
+
+            output.write_all(bp.0.into_bp_string(args.compression_level)?.as_bytes())?;
+            output.flush_if_stdout()?;
+        }
+        Commands::Transform(targs) => {
+            let mut input = input()?;
+            let mut output = output()?;
+            let mut bp = EditBlueprint::new(itob(&mut input)?);
+
+            if let Some(r) = targs.rotate {
+                bp.rotate(r)?;
+            }
+            if let Some(m) = targs.mirror {
+                bp.mirror(Axis::try_from_user_string(&m)?)?;
+            }
+            if let Some(m) = targs.move_by {
+                let d: Vec<f32> = parse_number_list(&m, "move offset")?;
+                match d[..] {
+                    [dx, dy] => bp.translate(dx, dy, 0.0)?,
+                    [dx, dy, dz] => bp.translate(dx, dy, dz)?,
+                    _ => return Err(some_error("Move offset must be \"dx,dy\" or \"dx,dy,dz\".")),
+                }
+            }
+            if targs.center_cursor {
+                bp.center_cursor();
+            }
+            if let Some(c) = targs.cursor {
+                match parse_number_list::<u32>(&c, "cursor offset")?[..] {
+                    [x, y] => bp.set_cursor_offset(x, y)?,
+                    _ => return Err(some_error("Cursor offset must be \"x,y\".")),
+                }
+            }
+
+            output.write_all(bp.0.into_bp_string(args.compression_level)?.as_bytes())?;
+            output.flush_if_stdout()?;
+        }
+        Commands::Merge(margs) => {
+            let mut output = output()?;
+            let read = |f: &str| -> anyhow::Result<Blueprint> {
+                Blueprint::new(&std::fs::read_to_string(f)?)
+            };
+            let mut bp = EditBlueprint::new(read(&margs.files[0])?);
+            for (i, f) in margs.files[1..].iter().enumerate() {
+                let (x, y) = match margs.at.get(i) {
+                    Some(at) => match parse_number_list::<i32>(at, "merge offset")?[..] {
+                        [x, y] => (x, y),
+                        _ => return Err(some_error("Merge offset must be \"x,y\".")),
+                    },
+                    None => (bp.0.data.header.dragbox_size_x as i32, 0),
+                };
+                bp.merge(read(f)?, x, y)?;
+            }
+
             output.write_all(bp.0.into_bp_string(args.compression_level)?.as_bytes())?;
             output.flush_if_stdout()?;
         }
+        Commands::Crop(cargs) => {
+            let mut input = input()?;
+            let mut output = output()?;
+            let mut bp = EditBlueprint::new(itob(&mut input)?);
+
+            match parse_number_list::<u32>(&cargs.region, "crop box")?[..] {
+                [x0, y0, x1, y1] => bp.crop(x0, y0, x1, y1)?,
+                _ => return Err(some_error("Crop box must be \"x0,y0,x1,y1\".")),
+            }
+
+            output.write_all(bp.0.into_bp_string(args.compression_level)?.as_bytes())?;
+            output.flush_if_stdout()?;
+        }
+        Commands::Split(_) => {
+            let mut input = input()?;
+            let bp = EditBlueprint::new(itob(&mut input)?);
+            let parts = bp.split_by_area();
+            match iof(&args.output) {
+                None => {
+                    let mut out = std::io::stdout();
+                    for p in parts {
+                        writeln!(out, "{}", p.into_bp_string(args.compression_level)?)?;
+                    }
+                }
+                Some(file) => {
+                    let path = std::path::Path::new(file);
+                    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+                    let ext = path
+                        .extension()
+                        .map(|e| format!(".{}", e.to_string_lossy()))
+                        .unwrap_or_default();
+                    for (i, p) in parts.into_iter().enumerate() {
+                        let name = path.with_file_name(format!("{}.{}{}", stem, i, ext));
+                        std::fs::write(name, p.into_bp_string(args.compression_level)?)?;
+                    }
+                }
+            }
+        }
+        Commands::Filter(fargs) => {
+            let mut input = input()?;
+            let mut output = output()?;
+            let mut bp = EditBlueprint::new(itob(&mut input)?);
+
+            let buildings: Vec<DSPItem> = match fargs.remove_building {
+                Some(b) => parse_user_list(&b)?,
+                None => vec![],
+            };
+            let recipes: Vec<DSPRecipe> = match fargs.remove_recipe {
+                Some(r) => parse_user_list(&r)?,
+                None => vec![],
+            };
+            let classes: Option<Vec<BuildingClass>> =
+                fargs.keep_class.map(|c| parse_user_list(&c)).transpose()?;
+            bp.retain(|b| {
+                let kind = b.kind().ok();
+                if kind.is_some_and(|k| buildings.contains(&k)) {
+                    return false;
+                }
+                if b.recipe().is_ok_and(|r| recipes.contains(&r)) {
+                    return false;
+                }
+                match (&classes, kind) {
+                    (Some(c), Some(k)) => c.contains(&BuildingClass::from(k)),
+                    (Some(_), None) => false,
+                    (None, _) => true,
+                }
+            });
+
+            output.write_all(bp.0.into_bp_string(args.compression_level)?.as_bytes())?;
+            output.flush_if_stdout()?;
+        }
+        Commands::Graph(gargs) => {
+            let format = GraphFormat::try_from_user_string(&gargs.format)?;
+            let locale = match &gargs.locale {
+                None => Locale::en,
+                Some(s) => Locale::try_from_user_string(s)?,
+            };
+            let _ = GLOBAL_SERIALIZATION_LOCALE.set(locale);
+            let mut input = input()?;
+            let mut output = output()?;
+            let bp = itob(&mut input)?;
+            Graph::new(&bp.data).write(&mut output, format)?;
+            output.flush_if_stdout()?;
+        }
+        Commands::Render(rargs) => {
+            let locale = match &rargs.locale {
+                None => Locale::en,
+                Some(s) => Locale::try_from_user_string(s)?,
+            };
+            let _ = GLOBAL_SERIALIZATION_LOCALE.set(locale);
+            let thumbnail = |path: &Option<String>, ext: &str| -> anyhow::Result<PathBuf> {
+                if let Some(path) = path {
+                    return Ok(path.into());
+                }
+                match iof(&args.input) {
+                    Some(file) => Ok(thumbnail_path(Path::new(file), ext)),
+                    None => Err(some_error(
+                        "Give a thumbnail file name, or use -i so it can go beside the blueprint.",
+                    )),
+                }
+            };
+            let png = rargs
+                .png
+                .as_ref()
+                .map(|p| thumbnail(p, "png"))
+                .transpose()?;
+            let jpeg = rargs
+                .jpeg
+                .as_ref()
+                .map(|p| thumbnail(p, "jpg"))
+                .transpose()?;
+            let mut input = input()?;
+            let bp = itob(&mut input)?;
+            let scene = Scene::new(&bp.data);
+            if let Some(path) = &rargs.svg {
+                write_svg(&scene, &mut File::create(path)?, rargs.scale)?;
+            }
+            if png.is_some() || jpeg.is_some() {
+                if !(1..=MAX_SIZE).contains(&rargs.size) {
+                    return Err(some_error(format!(
+                        "Thumbnail size must be 1 to {}, not {}.",
+                        MAX_SIZE, rargs.size
+                    )));
+                }
+                let img = rasterize(&scene, &bp.icons, rargs.size, rargs.size);
+                if let Some(path) = &png {
+                    write_png(&img, &mut File::create(path)?)?;
+                }
+                if let Some(path) = &jpeg {
+                    write_jpeg(&img, &mut File::create(path)?, rargs.quality)?;
+                }
+            }
+            if rargs.svg.is_none() && png.is_none() && jpeg.is_none() {
+                let mut output = output()?;
+                write_svg(&scene, &mut output, rargs.scale)?;
+                output.flush_if_stdout()?;
+            }
+        }
+        Commands::Check => {
+            let mut input = input()?;
+            let mut bp = itob(&mut input)?;
+            let problems = check::check(&mut bp);
+            let mut output = output()?;
+            for p in problems.iter() {
+                writeln!(output, "{}", p)?;
+            }
+            output.flush_if_stdout()?;
+            if !problems.is_empty() {
+                return Err(some_error(format!("Found {} problems.", problems.len())));
+            }
+        }
+        Commands::Rates(rargs) => {
+            let proliferator = Proliferator::try_from_user_string(&rargs.proliferator)?;
+            let mut input = input()?;
+            let mut bp = itob(&mut input)?;
+            let mut rates = GetRates::new(proliferator);
+            rates.visit_blueprint(&mut bp);
+            let mut output = output()?;
+            output.write_all(rates.rates.to_string().as_bytes())?;
+            output.flush_if_stdout()?;
+        }
+        Commands::Power => {
+            let mut input = input()?;
+            let mut bp = itob(&mut input)?;
+            let mut power = GetPower::new();
+            power.visit_blueprint(&mut bp);
+            let mut output = output()?;
+            output.write_all(power.0.to_string().as_bytes())?;
+            output.flush_if_stdout()?;
+        }
+        Commands::Bom(bargs) => {
+            let format = BomFormat::try_from_user_string(&bargs.format)?;
+            let mut input = input()?;
+            let mut output = output()?;
+            let mut bp = itob(&mut input)?;
+            let mut stats = GetStats::new();
+            stats.visit_blueprint(&mut bp);
+            let mut bom = BillOfMaterials::new(&stats.0);
+            if bargs.raw {
+                bom.add_raw_resources();
+            }
+            match format {
+                BomFormat::Text => output.write_all(bom.to_string().as_bytes())?,
+                #[cfg(feature = "dump")]
+                BomFormat::Json => output.write_all(bom.to_json()?.as_bytes())?,
+                #[cfg(not(feature = "dump"))]
+                BomFormat::Json => {
+                    return Err(some_error("JSON output needs the dump feature."));
+                }
+            }
+            output.flush_if_stdout()?;
+        }
         Commands::Info => {
             let mut input = input()?;
             let mut bp = itob(&mut input)?;
@@ -18,7 +18,7 @@ impl TryFromUserString for Locale {
     fn try_from_user_string(s: &str) -> anyhow::Result<Self> {
         Self::try_from(s).or_else(|_| {
             let locales = Self::iter()
-                .map(|e| <&'static str>::from(e))
+                .map(<&'static str>::from)
                 .collect::<Vec<_>>()
                 .join(", ");
             anyhow::bail!("Unknown locale '{}'. Supported locales: {}.", s, locales);
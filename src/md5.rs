@@ -103,7 +103,7 @@ impl MD5 {
         let mut c = self.s[2];
         let mut d = self.s[3];
 
-        for i in 0..64 {
+        for (i, shift) in S.iter().enumerate() {
             let mut f: u32;
             let g: usize;
             if i < 16 {
@@ -122,12 +122,12 @@ impl MD5 {
 
             f = f
                 .wrapping_add(a)
-                .wrapping_add(self.k(i as usize))
+                .wrapping_add(self.k(i))
                 .wrapping_add(words[g]);
             a = d;
             d = c;
             c = b;
-            b = b.wrapping_add(f.rotate_left(S[i as usize] as u32));
+            b = b.wrapping_add(f.rotate_left(*shift as u32));
         }
         self.s[0] = self.s[0].wrapping_add(a);
         self.s[1] = self.s[1].wrapping_add(b);
@@ -151,10 +151,10 @@ impl MD5 {
         }
 
         let mut out: MD5Hash = [0; 16];
-        (&mut out[0..4]).copy_from_slice(&self.s[0].to_le_bytes());
-        (&mut out[4..8]).copy_from_slice(&self.s[1].to_le_bytes());
-        (&mut out[8..12]).copy_from_slice(&self.s[2].to_le_bytes());
-        (&mut out[12..16]).copy_from_slice(&self.s[3].to_le_bytes());
+        out[0..4].copy_from_slice(&self.s[0].to_le_bytes());
+        out[4..8].copy_from_slice(&self.s[1].to_le_bytes());
+        out[8..12].copy_from_slice(&self.s[2].to_le_bytes());
+        out[12..16].copy_from_slice(&self.s[3].to_le_bytes());
         out
     }
 }
@@ -0,0 +1,105 @@
+use crate::data::{
+    enums::{BuildingClass, DSPItem},
+    power::PowerUsage,
+};
+use std::{collections::HashMap, fmt::Display};
+use strum::IntoEnumIterator;
+
+/// Power figures of a blueprint, in watts.
+#[derive(Default)]
+pub struct Power {
+    pub classes: HashMap<BuildingClass, PowerUsage>,
+    /// Charging of stations and logistics distributors. Included in the work figure of their
+    /// class.
+    pub charging: f64,
+    /// Buildings whose power use isn't known, so isn't in any figure.
+    pub uncounted: HashMap<DSPItem, usize>,
+}
+
+impl Power {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn add(&mut self, c: BuildingClass, p: PowerUsage) {
+        *self.classes.entry(c).or_default() += p;
+    }
+
+    pub fn add_charging(&mut self, c: BuildingClass, charging: f64) {
+        self.charging += charging;
+        self.add(
+            c,
+            PowerUsage {
+                work: charging,
+                ..Default::default()
+            },
+        );
+    }
+
+    pub fn add_uncounted(&mut self, i: DSPItem) {
+        *self.uncounted.entry(i).or_default() += 1;
+    }
+
+    pub fn total(&self) -> PowerUsage {
+        let mut t = PowerUsage::default();
+        for p in self.classes.values() {
+            t += *p;
+        }
+        t
+    }
+}
+
+/// Watts with a unit that keeps the number short.
+pub fn format_watts(w: f64) -> String {
+    let units = [(1e9, "GW"), (1e6, "MW"), (1e3, "kW")];
+    for (n, unit) in units {
+        if w.abs() >= n {
+            return format!("{:.2} {}", w / n, unit);
+        }
+    }
+    format!("{:.0} W", w)
+}
+
+fn write_usage(f: &mut std::fmt::Formatter<'_>, name: &str, p: &PowerUsage) -> std::fmt::Result {
+    writeln!(
+        f,
+        "{}: {} work, {} idle, {} generated",
+        name,
+        format_watts(p.work),
+        format_watts(p.idle),
+        format_watts(p.generation)
+    )
+}
+
+impl Display for Power {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Power per building class:")?;
+        for c in BuildingClass::iter() {
+            match self.classes.get(&c) {
+                Some(p) if *p != PowerUsage::default() => write_usage(f, c.as_ref(), p)?,
+                _ => (),
+            }
+        }
+        writeln!(f)?;
+        let t = self.total();
+        write_usage(f, "Total", &t)?;
+        writeln!(
+            f,
+            "Station and distributor charging: {}",
+            format_watts(self.charging)
+        )?;
+        writeln!(f, "Peak consumption: {}", format_watts(t.work))?;
+        writeln!(f, "Generation: {}", format_watts(t.generation))?;
+        writeln!(f, "Net at peak: {}", format_watts(t.generation - t.work))?;
+        if !self.uncounted.is_empty() {
+            let mut uncounted: Vec<_> = self.uncounted.iter().collect();
+            uncounted.sort_by_key(|(i, _)| **i as u16);
+            writeln!(f)?;
+            writeln!(f, "Not counted, power use unknown:")?;
+            for (i, n) in uncounted {
+                writeln!(f, "{}: {}", i.as_ref(), n)?;
+            }
+        }
+        Ok(())
+    }
+}
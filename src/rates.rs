@@ -0,0 +1,53 @@
+use crate::data::enums::DSPItem;
+use std::{collections::HashMap, fmt::Display};
+
+/// Items per minute.
+#[derive(Default)]
+pub struct Rates {
+    pub produced: HashMap<DSPItem, f64>,
+    pub consumed: HashMap<DSPItem, f64>,
+}
+
+impl Rates {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn add_produced(&mut self, i: DSPItem, per_minute: f64) {
+        *self.produced.entry(i).or_default() += per_minute;
+    }
+
+    pub fn add_consumed(&mut self, i: DSPItem, per_minute: f64) {
+        *self.consumed.entry(i).or_default() += per_minute;
+    }
+
+    /// Produced minus consumed.
+    pub fn net(&self, i: DSPItem) -> f64 {
+        self.produced.get(&i).unwrap_or(&0.0) - self.consumed.get(&i).unwrap_or(&0.0)
+    }
+}
+
+impl Display for Rates {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut items: Vec<DSPItem> = self
+            .produced
+            .keys()
+            .chain(self.consumed.keys())
+            .copied()
+            .collect();
+        items.sort_by_key(|i| *i as u16);
+        items.dedup();
+        writeln!(f, "Items per minute (produced, consumed, net):")?;
+        for i in items {
+            writeln!(
+                f,
+                "{}: {:.2}, {:.2}, {:+.2}",
+                i.as_ref(),
+                self.produced.get(&i).unwrap_or(&0.0),
+                self.consumed.get(&i).unwrap_or(&0.0),
+                self.net(i)
+            )?;
+        }
+        Ok(())
+    }
+}
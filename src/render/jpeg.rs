@@ -0,0 +1,329 @@
+use std::{f32::consts::PI, io::Write};
+
+use super::raster::Image;
+
+// Baseline JPEG, no subsampling. Tables are the example ones from the JPEG standard, annex K.
+// The luminance Huffman tables have codes for every symbol, so all components share them.
+
+const LUMA_QUANT: [u8; 64] = [
+    16, 11, 10, 16, 24, 40, 51, 61, //
+    12, 12, 14, 19, 26, 58, 60, 55, //
+    14, 13, 16, 24, 40, 57, 69, 56, //
+    14, 17, 22, 29, 51, 87, 80, 62, //
+    18, 22, 37, 56, 68, 109, 103, 77, //
+    24, 35, 55, 64, 81, 104, 113, 92, //
+    49, 64, 78, 87, 103, 121, 120, 101, //
+    72, 92, 95, 98, 112, 100, 103, 99, //
+];
+
+const CHROMA_QUANT: [u8; 64] = [
+    17, 18, 24, 47, 99, 99, 99, 99, //
+    18, 21, 26, 66, 99, 99, 99, 99, //
+    24, 26, 56, 99, 99, 99, 99, 99, //
+    47, 66, 99, 99, 99, 99, 99, 99, //
+    99, 99, 99, 99, 99, 99, 99, 99, //
+    99, 99, 99, 99, 99, 99, 99, 99, //
+    99, 99, 99, 99, 99, 99, 99, 99, //
+    99, 99, 99, 99, 99, 99, 99, 99, //
+];
+
+const DC_BITS: [u8; 16] = [0, 1, 5, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0];
+const DC_VALUES: [u8; 12] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11];
+
+const AC_BITS: [u8; 16] = [0, 2, 1, 3, 3, 2, 4, 3, 5, 5, 4, 4, 0, 0, 1, 0x7d];
+const AC_VALUES: [u8; 162] = [
+    0x01, 0x02, 0x03, 0x00, 0x04, 0x11, 0x05, 0x12, 0x21, 0x31, 0x41, 0x06, 0x13, 0x51, 0x61, 0x07,
+    0x22, 0x71, 0x14, 0x32, 0x81, 0x91, 0xa1, 0x08, 0x23, 0x42, 0xb1, 0xc1, 0x15, 0x52, 0xd1, 0xf0,
+    0x24, 0x33, 0x62, 0x72, 0x82, 0x09, 0x0a, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x25, 0x26, 0x27, 0x28,
+    0x29, 0x2a, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39, 0x3a, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49,
+    0x4a, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5a, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68, 0x69,
+    0x6a, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79, 0x7a, 0x83, 0x84, 0x85, 0x86, 0x87, 0x88, 0x89,
+    0x8a, 0x92, 0x93, 0x94, 0x95, 0x96, 0x97, 0x98, 0x99, 0x9a, 0xa2, 0xa3, 0xa4, 0xa5, 0xa6, 0xa7,
+    0xa8, 0xa9, 0xaa, 0xb2, 0xb3, 0xb4, 0xb5, 0xb6, 0xb7, 0xb8, 0xb9, 0xba, 0xc2, 0xc3, 0xc4, 0xc5,
+    0xc6, 0xc7, 0xc8, 0xc9, 0xca, 0xd2, 0xd3, 0xd4, 0xd5, 0xd6, 0xd7, 0xd8, 0xd9, 0xda, 0xe1, 0xe2,
+    0xe3, 0xe4, 0xe5, 0xe6, 0xe7, 0xe8, 0xe9, 0xea, 0xf1, 0xf2, 0xf3, 0xf4, 0xf5, 0xf6, 0xf7, 0xf8,
+    0xf9, 0xfa,
+];
+
+// Natural order index of each position in zigzag order.
+fn zigzag() -> [usize; 64] {
+    let mut out = [0; 64];
+    let mut k = 0;
+    for sum in 0..15usize {
+        let cells = (0..8).filter_map(|r: usize| {
+            let c = sum.checked_sub(r)?;
+            (c < 8).then_some(r * 8 + c)
+        });
+        // Odd diagonals run down, even ones up.
+        let cells: Vec<usize> = if sum % 2 == 1 {
+            cells.collect()
+        } else {
+            cells.rev().collect()
+        };
+        for i in cells {
+            out[k] = i;
+            k += 1;
+        }
+    }
+    out
+}
+
+// Same scaling as the IJG library: 50 is the tables as they are.
+fn scale_quant(table: &[u8; 64], quality: u8) -> [u16; 64] {
+    let q = quality.clamp(1, 100) as u32;
+    let scale = if q < 50 { 5000 / q } else { 200 - 2 * q };
+    table.map(|v| ((v as u32 * scale + 50) / 100).clamp(1, 255) as u16)
+}
+
+// Code and length of each symbol.
+fn huffman(bits: &[u8; 16], values: &[u8]) -> [(u16, u8); 256] {
+    let mut out = [(0, 0); 256];
+    let mut code = 0u16;
+    let mut values = values.iter();
+    for (len, n) in (1..=16u8).zip(bits) {
+        for _ in 0..*n {
+            out[*values.next().unwrap() as usize] = (code, len);
+            code += 1;
+        }
+        code <<= 1;
+    }
+    out
+}
+
+struct BitWriter {
+    out: Vec<u8>,
+    acc: u32,
+    count: u8,
+}
+
+impl BitWriter {
+    fn put(&mut self, bits: u16, len: u8) {
+        for i in (0..len).rev() {
+            self.acc = (self.acc << 1) | ((bits >> i) & 1) as u32;
+            self.count += 1;
+            if self.count == 8 {
+                let b = self.acc as u8;
+                self.out.push(b);
+                // 0xFF in entropy coded data must be followed by a zero.
+                if b == 0xFF {
+                    self.out.push(0);
+                }
+                self.acc = 0;
+                self.count = 0;
+            }
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        while self.count != 0 {
+            self.put(1, 1);
+        }
+        self.out
+    }
+}
+
+// Bits needed for `v`, and those bits as JPEG writes them.
+fn magnitude(v: i32) -> (u16, u8) {
+    let size = (32 - v.unsigned_abs().leading_zeros()) as u8;
+    let bits = if v < 0 { v - 1 } else { v };
+    ((bits & ((1 << size) - 1)) as u16, size)
+}
+
+struct Encoder {
+    zigzag: [usize; 64],
+    cos: [[f32; 8]; 8],
+    dc: [(u16, u8); 256],
+    ac: [(u16, u8); 256],
+    bits: BitWriter,
+}
+
+impl Encoder {
+    fn new() -> Self {
+        let mut cos = [[0.0; 8]; 8];
+        for (x, row) in cos.iter_mut().enumerate() {
+            for (u, c) in row.iter_mut().enumerate() {
+                *c = ((2 * x + 1) as f32 * u as f32 * PI / 16.0).cos();
+            }
+        }
+        Self {
+            zigzag: zigzag(),
+            cos,
+            dc: huffman(&DC_BITS, &DC_VALUES),
+            ac: huffman(&AC_BITS, &AC_VALUES),
+            bits: BitWriter {
+                out: vec![],
+                acc: 0,
+                count: 0,
+            },
+        }
+    }
+
+    // Quantized DCT of a block of samples, in natural order.
+    fn dct(&self, block: &[f32; 64], quant: &[u16; 64]) -> [i32; 64] {
+        let mut out = [0; 64];
+        for v in 0..8 {
+            for u in 0..8 {
+                let mut sum = 0.0;
+                for y in 0..8 {
+                    for x in 0..8 {
+                        sum += block[y * 8 + x] * self.cos[x][u] * self.cos[y][v];
+                    }
+                }
+                let cu = if u == 0 { 0.5f32.sqrt() } else { 1.0 };
+                let cv = if v == 0 { 0.5f32.sqrt() } else { 1.0 };
+                let f = sum * cu * cv / 4.0;
+                // Larger values have no Huffman codes.
+                let q = (f / quant[v * 8 + u] as f32).round() as i32;
+                out[v * 8 + u] = q.clamp(-1023, 1023);
+            }
+        }
+        out
+    }
+
+    fn block(&mut self, block: &[f32; 64], quant: &[u16; 64], last_dc: &mut i32) {
+        let coefs = self.dct(block, quant);
+        let diff = coefs[0] - *last_dc;
+        *last_dc = coefs[0];
+        let (bits, size) = magnitude(diff);
+        let (code, len) = self.dc[size as usize];
+        self.bits.put(code, len);
+        self.bits.put(bits, size);
+
+        let mut run = 0;
+        for k in 1..64 {
+            let v = coefs[self.zigzag[k]];
+            if v == 0 {
+                run += 1;
+                continue;
+            }
+            while run > 15 {
+                let (code, len) = self.ac[0xF0];
+                self.bits.put(code, len);
+                run -= 16;
+            }
+            let (bits, size) = magnitude(v);
+            let (code, len) = self.ac[(run << 4) | size as usize];
+            self.bits.put(code, len);
+            self.bits.put(bits, size);
+            run = 0;
+        }
+        if run > 0 {
+            let (code, len) = self.ac[0x00];
+            self.bits.put(code, len);
+        }
+    }
+}
+
+fn segment<W: Write>(w: &mut W, marker: u8, data: &[u8]) -> std::io::Result<()> {
+    w.write_all(&[0xFF, marker])?;
+    w.write_all(&(data.len() as u16 + 2).to_be_bytes())?;
+    w.write_all(data)
+}
+
+/// Writes `img` as a baseline JPEG. `quality` goes from 1 to 100.
+pub fn write_jpeg<W: Write>(img: &Image, w: &mut W, quality: u8) -> std::io::Result<()> {
+    let quant = [
+        scale_quant(&LUMA_QUANT, quality),
+        scale_quant(&CHROMA_QUANT, quality),
+    ];
+    let mut enc = Encoder::new();
+
+    w.write_all(&[0xFF, 0xD8])?;
+    segment(w, 0xE0, b"JFIF\0\x01\x01\0\0\x01\0\x01\0\0")?;
+    let mut dqt = vec![];
+    for (i, q) in quant.iter().enumerate() {
+        dqt.push(i as u8);
+        dqt.extend(enc.zigzag.iter().map(|&n| q[n] as u8));
+    }
+    segment(w, 0xDB, &dqt)?;
+    let (Ok(width), Ok(height)) = (u16::try_from(img.width), u16::try_from(img.height)) else {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "JPEG images can't be larger than 65535 pixels across",
+        ));
+    };
+    let mut sof = vec![8];
+    sof.extend_from_slice(&height.to_be_bytes());
+    sof.extend_from_slice(&width.to_be_bytes());
+    sof.extend_from_slice(&[3, 1, 0x11, 0, 2, 0x11, 1, 3, 0x11, 1]);
+    segment(w, 0xC0, &sof)?;
+    let mut dht = vec![0x00];
+    dht.extend_from_slice(&DC_BITS);
+    dht.extend_from_slice(&DC_VALUES);
+    dht.push(0x10);
+    dht.extend_from_slice(&AC_BITS);
+    dht.extend_from_slice(&AC_VALUES);
+    segment(w, 0xC4, &dht)?;
+    segment(w, 0xDA, &[3, 1, 0x00, 2, 0x00, 3, 0x00, 0, 63, 0])?;
+
+    let mut last_dc = [0; 3];
+    for by in (0..img.height).step_by(8) {
+        for bx in (0..img.width).step_by(8) {
+            let (mut luma, mut cb, mut cr) = ([0.0f32; 64], [0.0f32; 64], [0.0f32; 64]);
+            for (i, ((l, cb), cr)) in luma.iter_mut().zip(&mut cb).zip(&mut cr).enumerate() {
+                // Blocks past the edge repeat the last pixel.
+                let x = (bx + i % 8).min(img.width - 1);
+                let y = (by + i / 8).min(img.height - 1);
+                let c = img.pixels[y * img.width + x];
+                let (r, g, b) = (c.0 as f32, c.1 as f32, c.2 as f32);
+                *l = 0.299 * r + 0.587 * g + 0.114 * b - 128.0;
+                *cb = -0.168736 * r - 0.331264 * g + 0.5 * b;
+                *cr = 0.5 * r - 0.418688 * g - 0.081312 * b;
+            }
+            for (c, block) in [luma, cb, cr].iter().enumerate() {
+                enc.block(block, &quant[(c > 0) as usize], &mut last_dc[c]);
+            }
+        }
+    }
+    w.write_all(&enc.bits.finish())?;
+    w.write_all(&[0xFF, 0xD9])
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use crate::render::{raster::Image, Color};
+
+    use super::{huffman, magnitude, write_jpeg, zigzag, AC_BITS, AC_VALUES};
+
+    #[test]
+    fn tables() {
+        let z = zigzag();
+        assert_eq!(z[..10], [0, 1, 8, 16, 9, 2, 3, 10, 17, 24]);
+        assert_eq!(z[63], 63);
+        assert_eq!(z.iter().collect::<HashSet<_>>().len(), 64);
+
+        // Every run and size has a code, and none is the start of another.
+        let codes = huffman(&AC_BITS, &AC_VALUES);
+        assert_eq!(AC_VALUES.iter().collect::<HashSet<_>>().len(), 162);
+        let used: Vec<_> = AC_VALUES.iter().map(|v| codes[*v as usize]).collect();
+        for (a, la) in used.iter() {
+            for (b, lb) in used.iter() {
+                if la < lb {
+                    assert_ne!(b >> (lb - la), *a);
+                }
+            }
+        }
+
+        assert_eq!(magnitude(0), (0, 0));
+        assert_eq!(magnitude(5), (0b101, 3));
+        assert_eq!(magnitude(-5), (0b010, 3));
+    }
+
+    #[test]
+    fn jpeg() {
+        let img = Image::new(10, 9, Color(200, 40, 40));
+        let mut out = vec![];
+        write_jpeg(&img, &mut out, 90).unwrap();
+        assert_eq!(&out[..4], &[0xFF, 0xD8, 0xFF, 0xE0]);
+        assert_eq!(&out[6..11], b"JFIF\0");
+        assert_eq!(&out[out.len() - 2..], &[0xFF, 0xD9]);
+        // Width and height in the frame header.
+        let sof = out.windows(2).position(|w| w == [0xFF, 0xC0]).unwrap();
+        assert_eq!(&out[sof + 5..sof + 9], &[0, 9, 0, 10]);
+
+        let wide = Image::new(70000, 1, Color(0, 0, 0));
+        assert!(write_jpeg(&wide, &mut vec![], 90).is_err());
+    }
+}
@@ -0,0 +1,296 @@
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    data::{
+        blueprint::BlueprintData,
+        building::{Building, BuildingParam},
+        enums::{BuildingClass, DSPItem},
+    },
+    graph::NO_BUILDING,
+    locale::LocalizedEnumImpl,
+};
+
+mod font;
+pub mod jpeg;
+pub mod png;
+pub mod raster;
+pub mod svg;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Color(pub u8, pub u8, pub u8);
+
+impl Color {
+    pub const BACKGROUND: Self = Self(30, 34, 39);
+    pub const TEXT: Self = Self(240, 240, 240);
+    pub const BADGE: Self = Self(20, 90, 160);
+
+    pub fn hex(&self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+pub fn class_color(c: BuildingClass) -> Color {
+    match c {
+        BuildingClass::Assembler => Color(74, 144, 217),
+        BuildingClass::Smelter => Color(217, 130, 43),
+        BuildingClass::Belt => Color(154, 160, 166),
+        BuildingClass::Sorter => Color(224, 195, 65),
+        BuildingClass::Turret => Color(192, 57, 43),
+        BuildingClass::Other => Color(127, 140, 141),
+    }
+}
+
+/// Rough width and height in cells of a building facing north.
+pub fn footprint(i: DSPItem) -> (f32, f32) {
+    if i.is_station() || i.is_orbital_collector() {
+        return (4.0, 4.0);
+    }
+    match BuildingClass::from(i) {
+        BuildingClass::Assembler => return (3.0, 3.0),
+        BuildingClass::Smelter | BuildingClass::Turret => return (2.0, 2.0),
+        _ => (),
+    }
+    match i {
+        DSPItem::RecomposingAssembler | DSPItem::MatrixLab | DSPItem::SelfevolutionLab => {
+            (3.0, 3.0)
+        }
+        DSPItem::NegentropySmelter | DSPItem::Fractionator => (2.0, 2.0),
+        DSPItem::ChemicalPlant | DSPItem::QuantumChemicalPlant | DSPItem::OilRefinery => (3.0, 2.0),
+        DSPItem::MiniatureParticleCollider | DSPItem::MiningMachine => (3.0, 3.0),
+        DSPItem::AdvancedMiningMachine | DSPItem::EMRailEjector => (3.0, 3.0),
+        DSPItem::VerticalLaunchingSilo | DSPItem::ArtificialStar => (5.0, 5.0),
+        DSPItem::ThermalPowerPlant | DSPItem::MiniFusionPowerPlant => (2.0, 2.0),
+        DSPItem::RayReceiver | DSPItem::EnergyExchanger | DSPItem::SolarPanel => (2.0, 2.0),
+        DSPItem::DepotMKI | DSPItem::DepotMKII | DSPItem::StorageTank => (2.0, 2.0),
+        _ => (1.0, 1.0),
+    }
+}
+
+/// Unit vector pointing `yaw` degrees clockwise from north, with north being +y.
+pub fn direction(yaw: f32) -> (f32, f32) {
+    let r = yaw.to_radians();
+    (r.sin(), r.cos())
+}
+
+pub enum Shape {
+    /// A building centred on `x, y`, turned `yaw` degrees clockwise from north.
+    Building {
+        index: u32,
+        x: f32,
+        y: f32,
+        w: f32,
+        h: f32,
+        yaw: f32,
+        color: Color,
+        label: Vec<String>,
+    },
+    /// A run of belts, or a sorter, in the direction items move.
+    Line {
+        points: Vec<(f32, f32)>,
+        color: Color,
+    },
+}
+
+/// What a top-down picture of a blueprint shows, in blueprint cells with north being +y.
+pub struct Scene {
+    pub shapes: Vec<Shape>,
+    pub min: (f32, f32),
+    pub max: (f32, f32),
+}
+
+fn name<T: LocalizedEnumImpl + AsRef<str>>(t: &T) -> String {
+    t.localize().unwrap_or(t.as_ref()).to_owned()
+}
+
+// Station wares, or else the recipe. Localized if a locale is set.
+fn label(b: &Building) -> Vec<String> {
+    if let BuildingParam::Station(s) = &b.param {
+        return s
+            .valid_storage()
+            .iter()
+            .filter_map(|s| DSPItem::try_from(s.item_id).ok())
+            .map(|i| name(&i))
+            .collect();
+    }
+    b.recipe().ok().iter().map(name).collect()
+}
+
+fn belt_runs(data: &BlueprintData) -> Vec<Vec<&Building>> {
+    let belts: HashMap<u32, &Building> = data
+        .buildings
+        .iter()
+        .filter(|b| b.kind().is_ok_and(|k| k.is_belt()))
+        .map(|b| (b.header.index, b))
+        .collect();
+    let fed: HashSet<u32> = belts
+        .values()
+        .map(|b| b.header.output_object_index)
+        .filter(|i| *i != NO_BUILDING && belts.contains_key(i))
+        .collect();
+
+    // Runs start at belts no belt feeds. Whatever is left is a loop, started anywhere.
+    let in_order = data
+        .buildings
+        .iter()
+        .filter(|b| belts.contains_key(&b.header.index));
+    let starts = in_order
+        .clone()
+        .filter(|b| !fed.contains(&b.header.index))
+        .chain(in_order);
+    let mut seen = HashSet::new();
+    let mut runs = vec![];
+    for start in starts {
+        let mut run = vec![];
+        let mut next = Some(start);
+        while let Some(b) = next.filter(|b| seen.insert(b.header.index)) {
+            run.push(b);
+            next = belts.get(&b.header.output_object_index).copied();
+        }
+        if !run.is_empty() {
+            runs.push(run);
+        }
+    }
+    runs
+}
+
+impl Scene {
+    pub fn new(data: &BlueprintData) -> Self {
+        let mut shapes = vec![];
+        for b in data.buildings.iter() {
+            let Ok(kind) = b.kind() else {
+                continue;
+            };
+            let h = &b.header;
+            let class = BuildingClass::from(kind);
+            match class {
+                BuildingClass::Belt => (),
+                BuildingClass::Sorter => shapes.push(Shape::Line {
+                    points: vec![
+                        (h.local_offset_x, h.local_offset_y),
+                        (h.local_offset_x2, h.local_offset_y2),
+                    ],
+                    color: class_color(class),
+                }),
+                _ => {
+                    let (w, hgt) = footprint(kind);
+                    shapes.push(Shape::Building {
+                        index: h.index,
+                        x: h.local_offset_x,
+                        y: h.local_offset_y,
+                        w,
+                        h: hgt,
+                        yaw: h.yaw,
+                        color: class_color(class),
+                        label: label(b),
+                    })
+                }
+            }
+        }
+        for run in belt_runs(data) {
+            let mut points: Vec<(f32, f32)> = run
+                .iter()
+                .map(|b| (b.header.local_offset_x, b.header.local_offset_y))
+                .collect();
+            // Half a cell more at the end, so single belts show and runs show where they go.
+            let last = run[run.len() - 1];
+            let (dx, dy) = direction(last.header.yaw);
+            let (x, y) = points[points.len() - 1];
+            points.push((x + dx / 2.0, y + dy / 2.0));
+            shapes.push(Shape::Line {
+                points,
+                color: class_color(BuildingClass::Belt),
+            });
+        }
+
+        let header = &data.header;
+        let mut min = (0.0f32, 0.0f32);
+        let mut max = (
+            header.dragbox_size_x.saturating_sub(1) as f32,
+            header.dragbox_size_y.saturating_sub(1) as f32,
+        );
+        let mut extend = |x: f32, y: f32, r: f32| {
+            min = (min.0.min(x - r), min.1.min(y - r));
+            max = (max.0.max(x + r), max.1.max(y + r));
+        };
+        for s in shapes.iter() {
+            match s {
+                Shape::Building { x, y, w, h, .. } => extend(*x, *y, w.max(*h) / 2.0),
+                Shape::Line { points, .. } => {
+                    for (x, y) in points {
+                        extend(*x, *y, 0.5)
+                    }
+                }
+            }
+        }
+        // Offsets are a little off whole cells; don't let that show as slivers of padding.
+        let snap = |v: f32| (v * 2.0).round() / 2.0;
+        Self {
+            shapes,
+            min: (snap(min.0), snap(min.1)),
+            max: (snap(max.0), snap(max.1)),
+        }
+    }
+}
+
+/// Where the game's blueprint library looks for the thumbnail of a blueprint file: beside it,
+/// with `ext` in place of `.txt`.
+pub fn thumbnail_path(blueprint: &Path, ext: &str) -> PathBuf {
+    match blueprint.extension() {
+        Some(e) if e.eq_ignore_ascii_case("txt") => blueprint.with_extension(ext),
+        _ => {
+            let mut path = blueprint.as_os_str().to_owned();
+            path.push(".");
+            path.push(ext);
+            path.into()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::{Path, PathBuf};
+
+    use crate::testutil::example_station;
+
+    use super::{thumbnail_path, Scene, Shape};
+
+    #[test]
+    fn thumbnail_beside_blueprint() {
+        let path = |p: &str| thumbnail_path(Path::new(p), "jpg");
+        assert_eq!(
+            path("blueprints/72 iron.txt"),
+            PathBuf::from("blueprints/72 iron.jpg")
+        );
+        assert_eq!(
+            path("v1.2 smelters.TXT"),
+            PathBuf::from("v1.2 smelters.jpg")
+        );
+        assert_eq!(path("smelters.v2"), PathBuf::from("smelters.v2.jpg"));
+    }
+
+    #[test]
+    fn example_scene() {
+        let scene = Scene::new(&example_station().data);
+        let mut buildings = vec![];
+        let mut belts = 0;
+        let mut runs = 0;
+        for s in scene.shapes.iter() {
+            match s {
+                Shape::Building { index, label, .. } => buildings.push((*index, label.len())),
+                Shape::Line { points, .. } => {
+                    runs += 1;
+                    belts += points.len() - 1;
+                }
+            }
+        }
+        assert_eq!(buildings, vec![(12, 5)]);
+        assert_eq!(belts, 36);
+        // Every belt run starts at the station and goes out to the drag box edge.
+        assert_eq!(runs, 12);
+        assert_eq!(scene.min, (-1.0, -1.0));
+        assert_eq!(scene.max, (9.0, 9.0));
+    }
+}
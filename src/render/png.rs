@@ -0,0 +1,72 @@
+use std::io::Write;
+
+use flate2::{write::ZlibEncoder, Compression};
+
+use super::raster::Image;
+
+fn chunk<W: Write>(w: &mut W, kind: &[u8; 4], data: &[u8]) -> std::io::Result<()> {
+    w.write_all(&(data.len() as u32).to_be_bytes())?;
+    w.write_all(kind)?;
+    w.write_all(data)?;
+    let mut crc = crc32fast::Hasher::new();
+    crc.update(kind);
+    crc.update(data);
+    w.write_all(&crc.finalize().to_be_bytes())
+}
+
+/// Writes `img` as an 8 bit RGB PNG.
+pub fn write_png<W: Write>(img: &Image, w: &mut W) -> std::io::Result<()> {
+    w.write_all(b"\x89PNG\r\n\x1a\n")?;
+
+    let mut header = vec![];
+    header.extend_from_slice(&(img.width as u32).to_be_bytes());
+    header.extend_from_slice(&(img.height as u32).to_be_bytes());
+    // Bit depth, RGB, deflate, no filtering method to speak of, not interlaced.
+    header.extend_from_slice(&[8, 2, 0, 0, 0]);
+    chunk(w, b"IHDR", &header)?;
+
+    let mut z = ZlibEncoder::new(vec![], Compression::best());
+    for row in img.pixels.chunks(img.width) {
+        z.write_all(&[0])?; // No filter.
+        for c in row {
+            z.write_all(&[c.0, c.1, c.2])?;
+        }
+    }
+    chunk(w, b"IDAT", &z.finish()?)?;
+    chunk(w, b"IEND", &[])
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Read;
+
+    use flate2::read::ZlibDecoder;
+
+    use crate::render::{raster::Image, Color};
+
+    use super::write_png;
+
+    #[test]
+    fn png() {
+        let mut img = Image::new(3, 2, Color(1, 2, 3));
+        img.pixels[5] = Color(255, 0, 128);
+        let mut out = vec![];
+        write_png(&img, &mut out).unwrap();
+        assert_eq!(&out[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(&out[12..16], b"IHDR");
+        assert_eq!(&out[16..24], &[0, 0, 0, 3, 0, 0, 0, 2]);
+        assert_eq!(&out[29..33], &crc32fast::hash(&out[12..29]).to_be_bytes());
+
+        let len = u32::from_be_bytes(out[33..37].try_into().unwrap()) as usize;
+        assert_eq!(&out[37..41], b"IDAT");
+        let mut raw = vec![];
+        ZlibDecoder::new(&out[41..41 + len])
+            .read_to_end(&mut raw)
+            .unwrap();
+        assert_eq!(
+            raw,
+            [0, 1, 2, 3, 1, 2, 3, 1, 2, 3, 0, 1, 2, 3, 1, 2, 3, 255, 0, 128]
+        );
+        assert_eq!(&out[out.len() - 8..out.len() - 4], b"IEND");
+    }
+}
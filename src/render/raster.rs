@@ -0,0 +1,214 @@
+use crate::data::enums::DSPIcon;
+
+use super::{font, Color, Scene, Shape};
+
+/// Largest thumbnail width and height. JPEG allows up to 65535, but that's gigabytes of pixels.
+pub const MAX_SIZE: usize = 4096;
+
+/// Pixels, row by row from the top left.
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    pub pixels: Vec<Color>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, fill: Color) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![fill; width * height],
+        }
+    }
+
+    // Sets pixels in the box whose centres `inside` says are in.
+    fn fill<F: Fn(f32, f32) -> bool>(&mut self, bounds: [f32; 4], color: Color, inside: F) {
+        let [x0, y0, x1, y1] = bounds;
+        let xs = x0.floor().max(0.0) as usize..(x1.ceil().max(0.0) as usize).min(self.width);
+        let ys = y0.floor().max(0.0) as usize..(y1.ceil().max(0.0) as usize).min(self.height);
+        for y in ys {
+            for x in xs.clone() {
+                if inside(x as f32 + 0.5, y as f32 + 0.5) {
+                    self.pixels[y * self.width + x] = color;
+                }
+            }
+        }
+    }
+
+    fn rect(&mut self, x: usize, y: usize, w: usize, h: usize, color: Color) {
+        let b = [x as f32, y as f32, (x + w) as f32, (y + h) as f32];
+        self.fill(b, color, |_, _| true);
+    }
+
+    /// Rectangle centred on `cx, cy`, turned `yaw` degrees clockwise.
+    fn turned_rect(&mut self, c: (f32, f32), w: f32, h: f32, yaw: f32, color: Color) {
+        let r = (w * w + h * h).sqrt() / 2.0;
+        let (sin, cos) = yaw.to_radians().sin_cos();
+        let b = [c.0 - r, c.1 - r, c.0 + r, c.1 + r];
+        self.fill(b, color, |x, y| {
+            let (dx, dy) = (x - c.0, y - c.1);
+            let lx = cos * dx + sin * dy;
+            let ly = -sin * dx + cos * dy;
+            lx.abs() <= w / 2.0 && ly.abs() <= h / 2.0
+        });
+    }
+
+    fn segment(&mut self, a: (f32, f32), b: (f32, f32), width: f32, color: Color) {
+        let r = width / 2.0;
+        let bounds = [
+            a.0.min(b.0) - r,
+            a.1.min(b.1) - r,
+            a.0.max(b.0) + r,
+            a.1.max(b.1) + r,
+        ];
+        let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+        let len2 = dx * dx + dy * dy;
+        self.fill(bounds, color, |x, y| {
+            let t = if len2 == 0.0 {
+                0.0
+            } else {
+                (((x - a.0) * dx + (y - a.1) * dy) / len2).clamp(0.0, 1.0)
+            };
+            let (px, py) = (a.0 + t * dx - x, a.1 + t * dy - y);
+            px * px + py * py <= r * r
+        });
+    }
+
+    fn text(&mut self, x: usize, y: usize, s: &str, scale: usize, color: Color) {
+        for (n, c) in s.chars().enumerate() {
+            let left = x + n * (font::WIDTH + 1) * scale;
+            for (row, bits) in font::glyph(c).iter().enumerate() {
+                for col in 0..font::WIDTH {
+                    if bits & (0x10 >> col) != 0 {
+                        let (px, py) = (left + col * scale, y + row * scale);
+                        self.rect(px, py, scale, scale, color);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Up to four letters standing for a blueprint icon, or `None` for no icon.
+pub fn badge_text(icon: u32) -> Option<String> {
+    if icon == 0 {
+        return None;
+    }
+    let short = |name: &str| -> String {
+        let caps: String = name
+            .chars()
+            .filter(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+            .take(4)
+            .collect();
+        if caps.is_empty() {
+            name.chars().take(3).collect()
+        } else {
+            caps
+        }
+    };
+    let text = match DSPIcon::try_from(icon) {
+        Ok(DSPIcon::Item(i)) => short(i.as_ref()),
+        Ok(DSPIcon::Recipe(r)) => short(r.as_ref()),
+        Ok(DSPIcon::Signal(n)) => n.to_string(),
+        Ok(DSPIcon::Tech(n)) => format!("T{}", n % 1000),
+        _ => "?".to_owned(),
+    };
+    Some(text)
+}
+
+// Cells of empty space around the blueprint.
+const PAD: f32 = 1.0;
+
+/// Draws `scene` to fit a `width` by `height` picture, with `icons` as badges along the top.
+pub fn rasterize(scene: &Scene, icons: &[u32], width: usize, height: usize) -> Image {
+    let mut img = Image::new(width, height, Color::BACKGROUND);
+    let (w, h) = (
+        scene.max.0 - scene.min.0 + 2.0 * PAD,
+        scene.max.1 - scene.min.1 + 2.0 * PAD,
+    );
+    let scale = (width as f32 / w).min(height as f32 / h);
+    let (ox, oy) = (
+        (width as f32 - w * scale) / 2.0,
+        (height as f32 - h * scale) / 2.0,
+    );
+    // Image y goes down, blueprint y goes north.
+    let at = |x: f32, y: f32| {
+        (
+            ox + (x - scene.min.0 + PAD) * scale,
+            oy + (scene.max.1 - y + PAD) * scale,
+        )
+    };
+
+    for s in scene.shapes.iter() {
+        if let Shape::Building {
+            x,
+            y,
+            w,
+            h,
+            yaw,
+            color,
+            ..
+        } = s
+        {
+            img.turned_rect(at(*x, *y), w * scale, h * scale, *yaw, *color);
+        }
+    }
+    for s in scene.shapes.iter() {
+        if let Shape::Line { points, color } = s {
+            for pair in points.windows(2) {
+                let (a, b) = (at(pair[0].0, pair[0].1), at(pair[1].0, pair[1].1));
+                img.segment(a, b, (scale * 0.3).max(1.0), *color);
+            }
+        }
+    }
+
+    // Badges are a bit over a tenth of the picture high, letters scaled by whole pixels.
+    let px = (height / (10 * (font::HEIGHT + 2))).max(1);
+    let (pad, badge_h) = (px, (font::HEIGHT + 2) * px);
+    let mut left = pad;
+    for text in icons.iter().filter_map(|i| badge_text(*i)) {
+        let badge_w = (text.chars().count() * (font::WIDTH + 1) + 1) * px;
+        if left + badge_w > width {
+            break;
+        }
+        img.rect(left, pad, badge_w, badge_h, Color::BADGE);
+        img.text(left + px, pad + px, &text, px, Color::TEXT);
+        left += badge_w + pad;
+    }
+    img
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        data::enums::DSPItem,
+        render::{Color, Scene},
+        testutil::example_station,
+    };
+
+    use super::{badge_text, rasterize};
+
+    #[test]
+    fn badges() {
+        let ils = DSPItem::InterstellarLogisticsStation as u32;
+        assert_eq!(badge_text(ils).unwrap(), "ILS");
+        assert_eq!(badge_text(0), None);
+        assert_eq!(badge_text(401).unwrap(), "401");
+    }
+
+    #[test]
+    fn raster() {
+        let mut bp = example_station();
+        bp.icons = [DSPItem::InterstellarLogisticsStation as u32, 0, 0, 0, 0];
+        let img = rasterize(&Scene::new(&bp.data), &bp.icons, 120, 100);
+        assert_eq!(img.pixels.len(), 120 * 100);
+        let at = |x: usize, y: usize| img.pixels[y * 120 + x];
+        // The station is in the middle, belts leave it to the north.
+        assert_eq!(at(60, 50), Color(127, 140, 141));
+        assert_eq!(at(60, 15), Color(154, 160, 166));
+        assert_eq!(at(119, 99), Color::BACKGROUND);
+        // One badge in the top left corner.
+        assert_eq!(at(1, 1), Color::BADGE);
+        assert!(img.pixels[..120 * 10].contains(&Color::TEXT));
+    }
+}
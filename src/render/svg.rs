@@ -0,0 +1,136 @@
+use std::io::Write;
+
+use crate::graph::xml_escape;
+
+use super::{Color, Scene, Shape};
+
+// Cells of empty space around the blueprint.
+const PAD: f32 = 1.0;
+
+/// Writes `scene` as SVG, `scale` pixels to a cell.
+pub fn write_svg<W: Write>(scene: &Scene, w: &mut W, scale: f32) -> std::io::Result<()> {
+    let width = (scene.max.0 - scene.min.0 + 2.0 * PAD) * scale;
+    let height = (scene.max.1 - scene.min.1 + 2.0 * PAD) * scale;
+    // SVG y goes down, blueprint y goes north.
+    let at = |x: f32, y: f32| {
+        (
+            (x - scene.min.0 + PAD) * scale,
+            (scene.max.1 - y + PAD) * scale,
+        )
+    };
+
+    writeln!(
+        w,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{:.0}" height="{:.0}" viewBox="0 0 {:.0} {:.0}">"#,
+        width, height, width, height
+    )?;
+    writeln!(
+        w,
+        r#"  <rect width="100%" height="100%" fill="{}"/>"#,
+        Color::BACKGROUND.hex()
+    )?;
+
+    for s in scene.shapes.iter() {
+        if let Shape::Building {
+            index,
+            x,
+            y,
+            w: bw,
+            h: bh,
+            yaw,
+            color,
+            ..
+        } = s
+        {
+            let (px, py) = at(*x, *y);
+            writeln!(
+                w,
+                r#"  <rect id="b{}" x="{:.1}" y="{:.1}" width="{:.1}" height="{:.1}" transform="translate({:.1} {:.1}) rotate({:.1})" fill="{}" stroke="{}"/>"#,
+                index,
+                -bw * scale / 2.0,
+                -bh * scale / 2.0,
+                bw * scale,
+                bh * scale,
+                px,
+                py,
+                yaw,
+                color.hex(),
+                Color::BACKGROUND.hex()
+            )?;
+        }
+    }
+
+    for s in scene.shapes.iter() {
+        if let Shape::Line { points, color } = s {
+            let points: Vec<String> = points
+                .iter()
+                .map(|(x, y)| {
+                    let (px, py) = at(*x, *y);
+                    format!("{:.1},{:.1}", px, py)
+                })
+                .collect();
+            writeln!(
+                w,
+                r#"  <polyline points="{}" fill="none" stroke="{}" stroke-width="{:.1}" stroke-linecap="round" stroke-linejoin="round"/>"#,
+                points.join(" "),
+                color.hex(),
+                scale * 0.3
+            )?;
+        }
+    }
+
+    let font = scale * 0.6;
+    for s in scene.shapes.iter() {
+        let Shape::Building { x, y, label, .. } = s else {
+            continue;
+        };
+        if label.is_empty() {
+            continue;
+        }
+        let (px, py) = at(*x, *y);
+        let top = py - font * (label.len() as f32 - 1.0) / 2.0;
+        writeln!(
+            w,
+            r#"  <text x="{:.1}" y="{:.1}" font-family="sans-serif" font-size="{:.1}" fill="{}" text-anchor="middle" dominant-baseline="middle">"#,
+            px,
+            top,
+            font,
+            Color::TEXT.hex()
+        )?;
+        for (i, line) in label.iter().enumerate() {
+            let dy = if i == 0 { 0.0 } else { font };
+            writeln!(
+                w,
+                r#"    <tspan x="{:.1}" dy="{:.1}">{}</tspan>"#,
+                px,
+                dy,
+                xml_escape(line)
+            )?;
+        }
+        writeln!(w, "  </text>")?;
+    }
+    writeln!(w, "</svg>")
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{render::Scene, testutil::example_station};
+
+    use super::write_svg;
+
+    #[test]
+    fn example_svg() {
+        let scene = Scene::new(&example_station().data);
+        let mut out = vec![];
+        write_svg(&scene, &mut out, 10.0).unwrap();
+        let svg = String::from_utf8(out).unwrap();
+        assert!(
+            svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="120" height="120""#)
+        );
+        // The station is 4 cells wide, centred 5 cells plus padding from the corner.
+        assert!(svg.contains(r#"<rect id="b12" x="-20.0" y="-20.0" width="40.0" height="40.0" transform="translate(60.0 60.0) rotate(0.0)""#));
+        assert_eq!(svg.matches("<polyline ").count(), 12);
+        assert_eq!(svg.matches("<tspan ").count(), 5);
+        assert!(svg.trim_end().ends_with("</svg>"));
+    }
+}
@@ -6,6 +6,7 @@ pub struct Stats {
     pub buildings: HashMap<DSPItem, usize>,
     pub recipes: HashMap<DSPRecipe, usize>,
     pub station_wares: HashMap<DSPItem, usize>,
+    pub storage_contents: HashMap<DSPItem, usize>,
 }
 
 impl Stats {
@@ -46,18 +47,25 @@ impl Stats {
     pub fn add_station_ware(&mut self, i: DSPItem) {
         Self::incmap(&mut self.station_wares, i)
     }
+
+    pub fn add_storage_content(&mut self, i: DSPItem) {
+        Self::incmap(&mut self.storage_contents, i)
+    }
 }
 
 impl Display for Stats {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         writeln!(f, "Buildings:")?;
         Self::printmap(f, &self.buildings)?;
-        writeln!(f, "")?;
+        writeln!(f)?;
         writeln!(f, "Recipes:")?;
         Self::printmap(f, &self.recipes)?;
-        writeln!(f, "")?;
+        writeln!(f)?;
         writeln!(f, "Logistic station wares:")?;
         Self::printmap(f, &self.station_wares)?;
+        writeln!(f)?;
+        writeln!(f, "Depot, tank and distributor contents:")?;
+        Self::printmap(f, &self.storage_contents)?;
         Ok(())
     }
 }
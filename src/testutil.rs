@@ -1,4 +1,15 @@
-use std::{fs::File, io::Read, path::PathBuf};
+use std::{
+    fs::File,
+    io::{Cursor, Read},
+    path::PathBuf,
+};
+
+use binrw::BinReaderExt;
+
+use crate::{
+    blueprint::Blueprint,
+    data::{building::Building, enums::DSPItem},
+};
 
 pub fn get_file(f: &str) -> Vec<u8> {
     let mut p = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
@@ -8,3 +19,35 @@ pub fn get_file(f: &str) -> Vec<u8> {
     File::open(p).unwrap().read_to_end(&mut res).unwrap();
     res
 }
+
+// Raw little-endian bytes of a single building with the given parameters and an otherwise
+// zeroed header.
+pub fn raw_building(item: DSPItem, params: &[u32]) -> Vec<u8> {
+    let mut out = vec![];
+    out.extend_from_slice(&0u32.to_le_bytes()); // index
+    out.push(0); // area_index
+    for _ in 0..8 {
+        out.extend_from_slice(&0f32.to_le_bytes()); // offsets and yaws
+    }
+    out.extend_from_slice(&u16::from(item).to_le_bytes());
+    out.extend_from_slice(&0u16.to_le_bytes()); // model_index
+    out.extend_from_slice(&0u32.to_le_bytes()); // output_object_index
+    out.extend_from_slice(&0u32.to_le_bytes()); // input_object_index
+    out.extend_from_slice(&[0; 6]); // slots and offsets
+    out.extend_from_slice(&0u16.to_le_bytes()); // recipe_id
+    out.extend_from_slice(&0u16.to_le_bytes()); // filter_id
+    out.extend_from_slice(&(params.len() as u16).to_le_bytes());
+    for p in params {
+        out.extend_from_slice(&p.to_le_bytes());
+    }
+    out
+}
+
+pub fn building(item: DSPItem, params: &[u32]) -> Building {
+    Cursor::new(raw_building(item, params)).read_le().unwrap()
+}
+
+pub fn example_station() -> Blueprint {
+    let f = get_file("Example interstellar station 1.txt");
+    Blueprint::new(std::str::from_utf8(&f).unwrap()).unwrap()
+}
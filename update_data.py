@@ -81,3 +81,46 @@ make_locale("src/locale/data/en/items.rs", items, "DSPItem", lambda i: i.en_name
 make_locale("src/locale/data/cn/items.rs", items, "DSPItem", lambda i: i.cn_name)
 make_locale("src/locale/data/en/recipes.rs", recipes, "DSPRecipe", lambda i: i.en_name)
 make_locale("src/locale/data/cn/recipes.rs", recipes, "DSPRecipe", lambda i: i.cn_name)
+
+
+# Recipe details. Items are referred to by English name, which we turn into enum IDs.
+
+item_ids = {i.en_name: i.id for i in items}
+recipe_ids = {r.number: r.id for r in recipes}
+
+def parse_counts(s):
+    out = []
+    for part in s.split(", "):
+        name, count = part.rsplit("*", 1)
+        assert name in item_ids, f"Unknown item {name}"
+        out.append((item_ids[name], int(count)))
+    return out
+
+def make_recipe_details(src, dst):
+    f = open(dst, "w")
+    f.write("&[\n")
+    seen = set()
+    for line in open(src).readlines():
+        line = line.strip()
+        if not line or line.startswith("#"):
+            continue
+        number, facility, time, extra, inputs, outputs = map(str.strip, line.split("|"))
+        number = int(number)
+        assert number in recipe_ids, f"Unknown recipe {number}"
+        assert extra in ["yes", "no"]
+        seen.add(number)
+        counts = lambda l: ", ".join(f"(DSPItem::{i}, {n})" for (i, n) in l)
+        f.write(f"    RecipeInfo {{\n")
+        f.write(f"        recipe: DSPRecipe::{recipe_ids[number]},\n")
+        f.write(f"        facility: Facility::{facility},\n")
+        f.write(f"        time: {float(time)},\n")
+        f.write(f"        extra_products: {'true' if extra == 'yes' else 'false'},\n")
+        f.write(f"        inputs: &[{counts(parse_counts(inputs))}],\n")
+        f.write(f"        outputs: &[{counts(parse_counts(outputs))}],\n")
+        f.write(f"    }},\n")
+    f.write("]\n")
+    f.close()
+    missing = set(recipe_ids) - seen
+    assert not missing, f"No details for recipes {sorted(missing)}"
+
+make_recipe_details("data/recipe_details.txt", "src/data/recipe/data/recipes.rs")